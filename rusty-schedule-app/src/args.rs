@@ -10,6 +10,8 @@ pub struct ScheduleCli {
 #[derive(Subcommand, Clone, Debug)]
 pub enum ScheduleCommand {
     Run,
+    /// Prints the streaks and weekly completion of every reminder.
+    Stats,
     #[cfg(feature = "tui")]
     UserInterface,
 }
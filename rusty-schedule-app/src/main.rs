@@ -1,12 +1,12 @@
 #![windows_subsystem = "windows"]
-use std::{io::{self, Write}, sync::mpsc::{channel, Receiver, Sender}, thread::JoinHandle, time::Duration};
+use std::{collections::HashMap, io::{self, Write}, path::PathBuf, sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender}, thread::JoinHandle, time::Duration};
 
 use args::{ScheduleCli, ScheduleCommand};
 use clap::Parser;
 use crossterm::event::{self, Event, KeyCode};
 use directories::ProjectDirs;
 use notify_rust::Notification;
-use rusty_schedule_core::{Notifier, NotifierBuilder};
+use rusty_schedule_core::{ActionExecutor, Channel, FiredReminder, Notifier, OccurrenceKey, ReminderAction, SystemExecutor};
 #[cfg(feature = "tray")]
 use tray_icon::{TrayIconBuilder, TrayIcon, TrayIconEvent, ClickType, Icon, menu::{Menu, MenuItem, MenuEvent, Submenu}};
 #[cfg(feature = "tray")]
@@ -21,6 +21,7 @@ fn main() -> std::io::Result<()> {
     if let Some(command) = command.command {
         match command {
             ScheduleCommand::Run => run(),
+            ScheduleCommand::Stats => stats(),
             #[cfg(feature = "tui")]
             ScheduleCommand::UserInterface => {
                 tui::tui_setup()?;
@@ -35,12 +36,13 @@ fn run() -> io::Result<()> {
     if let Some(dirs) = ProjectDirs::from("", "", "Rusty Notifier") {
         let data_path = dirs.data_dir();
         let (listener_sender, listener_receiver) = channel();
-        let listener_handler = match Notifier::load(data_path.join("reminders.json")) {
-            Ok(notifier) => listen(notifier, listener_receiver),
+        let reminders_path = data_path.join("reminders.json");
+        let listener_handler = match Notifier::load(&reminders_path) {
+            Ok(notifier) => listen(notifier, reminders_path, listener_sender.clone(), listener_receiver),
             Err(e) => panic!("Error loading reminders: {e}"),
         };
         #[cfg(feature = "tray")]
-        create_tray_icon();
+        create_tray_icon(listener_sender);
         #[cfg(not(feature = "tray"))]
         controls(
             listener_handler,
@@ -52,37 +54,238 @@ fn run() -> io::Result<()> {
     }
 }
 
+/// How many weeks of completion `stats` prints.
+const STATS_WEEKS: usize = 4;
+
+fn stats() -> io::Result<()> {
+    if let Some(dirs) = ProjectDirs::from("", "", "Rusty Notifier") {
+        let reminders_path = dirs.data_dir().join("reminders.json");
+        let notifier = match Notifier::load(&reminders_path) {
+            Ok(notifier) => notifier,
+            Err(e) => panic!("Error loading reminders: {e}"),
+        };
+        for reminder in notifier.reminders() {
+            let streak = notifier.streak(reminder.id());
+            let weeks = notifier.weekly_completion(reminder.id(), STATS_WEEKS).iter()
+                .map(|week| match week.rate() {
+                    Some(rate) => format!("{:.0}%", rate * 100.0),
+                    None => "-".into(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!("{}: streak {} (longest {}), last {STATS_WEEKS} weeks {weeks}", reminder.title, streak.current, streak.longest);
+        }
+        Ok(())
+    } else {
+        panic!("No home directory found");
+    }
+}
+
+/// Longest the listener sleeps for between checks for reminders.
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+/// How long reminders are snoozed for from their notification.
+const SNOOZE: chrono::Duration = chrono::Duration::minutes(10);
+
+/// Events sent to the listener. Answers without an occurrence, from the console or tray icon,
+/// answer the latest notified occurrence not answered yet.
 enum ReminderEvent {
     Exit,
+    Snooze(Option<OccurrenceKey>),
+    Acknowledge(Option<OccurrenceKey>),
+    Fail(Option<OccurrenceKey>),
+    /// The notification of the occurrence was dismissed without answering it.
+    Closed(OccurrenceKey),
 }
 
-fn listen(mut notifier: Notifier, mut receiver: Receiver<ReminderEvent>) -> JoinHandle<()> {
+fn listen(
+    mut notifier: Notifier,
+    reminders_path: PathBuf,
+    sender: Sender<ReminderEvent>,
+    receiver: Receiver<ReminderEvent>,
+) -> JoinHandle<()> {
     std::thread::spawn(move || {
-        'main: loop {
-            while let Ok(event) = receiver.try_recv() {
-                match event {
-                    ReminderEvent::Exit => break 'main,
-                }
-            }
-            for reminder in notifier.check_reminders() {
+        let mut unanswered: Vec<OccurrenceKey> = Vec::new();
+        let mut notifications = HashMap::new();
+        loop {
+            // Only occurrences from today are answered from the console or tray icon.
+            let today = notifier.now().date_naive();
+            unanswered.retain(|key| key.scheduled.date() >= today);
+            let check = notifier.check_reminders();
+            let changed = !check.fired().is_empty() || !check.missed().is_empty();
+            if !check.missed().is_empty() {
+                let missed = check.missed().iter()
+                    .map(|occurrence| format!("{} ({})", occurrence.title(), occurrence.scheduled.format("%a %H:%M")))
+                    .collect::<Vec<_>>()
+                    .join("\n");
                 Notification::new()
                     .appname("REMINDER")
-                    .summary(&reminder.title)
-                    .body(&reminder.content)
+                    .summary("MISSED REMINDERS")
+                    .body(&missed)
                     .timeout(0)
                     .show().unwrap();
             }
-            std::thread::sleep(Duration::from_millis(200));
+            for fired in check {
+                let key = fired.key();
+                unanswered.retain(|unanswered| *unanswered != key);
+                unanswered.push(key);
+                notify(&fired, &sender, &mut notifications);
+                for e in fired.run_actions(&mut SystemExecutor) {
+                    println!("{e}");
+                }
+            }
+            if changed {
+                if let Err(e) = notifier.save_state(&reminders_path) {
+                    println!("There was a problem saving reminder state: {e}");
+                }
+            }
+            // Waking up at least every `MAX_SLEEP` notices the wall clock jumping, or the system
+            // resuming from suspend, since waiting is measured on a monotonic clock.
+            let sleep = notifier.next_check()
+                .map(|next_check| (next_check - notifier.now()).to_std().unwrap_or_default())
+                .map_or(MAX_SLEEP, |sleep| sleep.min(MAX_SLEEP));
+            let event = receiver.recv_timeout(sleep);
+            if let Ok(
+                ReminderEvent::Acknowledge(Some(key))
+                | ReminderEvent::Snooze(Some(key))
+                | ReminderEvent::Fail(Some(key))
+                | ReminderEvent::Closed(key)
+            ) = &event {
+                // Answered or dismissed from its notification, which is gone along with the thread
+                // waiting on it.
+                notifications.remove(key);
+            }
+            match event {
+                Ok(ReminderEvent::Exit) | Err(RecvTimeoutError::Disconnected) => {
+                    if let Err(e) = notifier.save_state(&reminders_path) {
+                        println!("There was a problem saving reminder state: {e}");
+                    }
+                    break;
+                },
+                Ok(ReminderEvent::Snooze(key)) => {
+                    if let Some(key) = answered(&mut unanswered, key) {
+                        let until = notifier.now() + SNOOZE;
+                        notifier.snooze_occurrence(&key, until);
+                        if let Some(reminder) = notifier.get(&key.reminder) {
+                            println!("Snoozed {} until {}", reminder.title, until.format("%H:%M"));
+                        }
+                        if let Err(e) = notifier.save_state(&reminders_path) {
+                            println!("There was a problem saving reminder state: {e}");
+                        }
+                    }
+                },
+                Ok(ReminderEvent::Acknowledge(key)) => {
+                    if let Some(key) = answered(&mut unanswered, key) {
+                        notifier.acknowledge(&key);
+                        if let Some(reminder) = notifier.get(&key.reminder) {
+                            println!("Marked {} done", reminder.title);
+                        }
+                        if let Err(e) = notifier.save_state(&reminders_path) {
+                            println!("There was a problem saving reminder state: {e}");
+                        }
+                    }
+                },
+                Ok(ReminderEvent::Fail(key)) => {
+                    if let Some(key) = answered(&mut unanswered, key) {
+                        notifier.fail(&key);
+                        if let Some(reminder) = notifier.get(&key.reminder) {
+                            println!("Marked {} failed", reminder.title);
+                        }
+                        if let Err(e) = notifier.save_state(&reminders_path) {
+                            println!("There was a problem saving reminder state: {e}");
+                        }
+                    }
+                },
+                Ok(ReminderEvent::Closed(key)) => unanswered.retain(|unanswered| *unanswered != key),
+                Err(RecvTimeoutError::Timeout) => {},
+            }
         }
     })
 }
 
+/// Takes the occurrence an event answers out of those not answered yet, the latest one when the
+/// event names none.
+fn answered(unanswered: &mut Vec<OccurrenceKey>, key: Option<OccurrenceKey>) -> Option<OccurrenceKey> {
+    match key {
+        Some(key) => {
+            unanswered.retain(|unanswered| *unanswered != key);
+            Some(key)
+        },
+        None => unanswered.pop(),
+    }
+}
+
+/// Shows the reminder, which can be snoozed, marked done, or marked failed when it is a spaced
+/// repetition, from its notification where supported, and otherwise from the console or tray icon.
+///
+/// Escalated reminders are also delivered through the channel of their escalation. Notifying an
+/// occurrence again replaces its notification in `notifications`, by the identifiers of those
+/// still shown, so only one thread waits on it.
+fn notify(fired: &FiredReminder, sender: &Sender<ReminderEvent>, notifications: &mut HashMap<OccurrenceKey, u32>) {
+    let summary = match fired.context() {
+        Some(context) => format!("{} ({context})", fired.title),
+        None => fired.title.clone(),
+    };
+    let escalation = fired.reminder.escalation.as_ref().filter(|_| fired.escalated());
+    match escalation.and_then(|escalation| escalation.channel.as_ref()) {
+        Some(Channel::Console) => println!("\x07{summary}: {}", fired.content),
+        Some(Channel::Open(open)) => {
+            if let Err(e) = SystemExecutor.execute(fired, &ReminderAction::Open(open.clone())) {
+                println!("There was a problem escalating {summary}: {e}");
+            }
+        },
+        Some(Channel::Notification) | None => {},
+    }
+    let mut notification = Notification::new();
+    notification
+        .appname("REMINDER")
+        .summary(&summary)
+        .body(&fired.content)
+        .timeout(0);
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        if let Some(urgency) = escalation.and_then(|escalation| escalation.urgency) {
+            notification.urgency(match urgency {
+                rusty_schedule_core::Urgency::Low => notify_rust::Urgency::Low,
+                rusty_schedule_core::Urgency::Normal => notify_rust::Urgency::Normal,
+                rusty_schedule_core::Urgency::Critical => notify_rust::Urgency::Critical,
+            });
+        }
+        notification.action("done", "Done").action("snooze", "Snooze");
+        if fired.reminder.spaced.is_some() {
+            notification.action("failed", "Failed");
+        }
+        let key = fired.key();
+        if let Some(id) = notifications.get(&key) {
+            notification.id(*id);
+        }
+        let handle = notification.show().unwrap();
+        if notifications.insert(key.clone(), handle.id()).is_some() {
+            return;
+        }
+        let sender = sender.clone();
+        std::thread::spawn(move || handle.wait_for_action(|action| {
+            let _ = match action {
+                "done" => sender.send(ReminderEvent::Acknowledge(Some(key))),
+                "snooze" => sender.send(ReminderEvent::Snooze(Some(key))),
+                "failed" => sender.send(ReminderEvent::Fail(Some(key))),
+                "__closed" => sender.send(ReminderEvent::Closed(key)),
+                _ => Ok(()),
+            };
+        }));
+    }
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    {
+        let _ = (sender, notifications);
+        notification.show().unwrap();
+    }
+}
+
 #[cfg(not(feature = "tray"))]
 fn controls(
     listener_handler: JoinHandle<()>,
     listener_sender: Sender<ReminderEvent>,
 ) -> io::Result<()> {
-    println!("Reminders listening... Press ESC to stop.");
+    println!("Reminders listening... Press D to mark the latest reminder done, F failed, S to snooze it, ESC to stop.");
     loop {
         if event::poll(std::time::Duration::from_millis(100))? {
             let event = event::read()?;
@@ -92,13 +295,16 @@ fn controls(
                         listener_sender.send(ReminderEvent::Exit).unwrap();
                         break;
                     },
+                    KeyCode::Char('d') => listener_sender.send(ReminderEvent::Acknowledge(None)).unwrap(),
+                    KeyCode::Char('f') => listener_sender.send(ReminderEvent::Fail(None)).unwrap(),
+                    KeyCode::Char('s') => listener_sender.send(ReminderEvent::Snooze(None)).unwrap(),
                     _ => {},
                 }
             }
         }
     }
     print!("Closing listeners...");
-    io::stdout().flush();
+    io::stdout().flush()?;
     loop {
         if listener_handler.is_finished() {
             break;
@@ -109,10 +315,16 @@ fn controls(
 }
 
 #[cfg(feature = "tray")]
-fn create_tray_icon() {
+fn create_tray_icon(listener_sender: Sender<ReminderEvent>) {
     let tray_menu = Menu::new();
+    let done_menu_item = Box::new(MenuItem::new("Done", true, None));
+    let failed_menu_item = Box::new(MenuItem::new("Failed", true, None));
+    let snooze_menu_item = Box::new(MenuItem::new("Snooze", true, None));
     let quit_menu_item = Box::new(MenuItem::new("Quit", true, None));
 
+    tray_menu.append(done_menu_item.as_ref());
+    tray_menu.append(failed_menu_item.as_ref());
+    tray_menu.append(snooze_menu_item.as_ref());
     tray_menu.append(quit_menu_item.as_ref());
     let tray_icon = TrayIconBuilder::new()
         .with_menu(Box::new(tray_menu));
@@ -134,6 +346,12 @@ fn create_tray_icon() {
         if let Ok(event) = MenuEvent::receiver().try_recv() {
             if event.id() == quit_menu_item.id() {
                 event_loop.exit();
+            } else if event.id() == done_menu_item.id() {
+                let _ = listener_sender.send(ReminderEvent::Acknowledge(None));
+            } else if event.id() == failed_menu_item.id() {
+                let _ = listener_sender.send(ReminderEvent::Fail(None));
+            } else if event.id() == snooze_menu_item.id() {
+                let _ = listener_sender.send(ReminderEvent::Snooze(None));
             }
         }
     });
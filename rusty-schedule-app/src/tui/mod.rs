@@ -104,14 +104,9 @@ impl<'a> UserInterfaceState<'a> {
             } else {
                 NaiveTime::default()
             };
-            builder = builder.notify(time, Reminder {
-                title: task.title.value().into(),
-                content: task.content.yank_text(),
-                weekdays: None,
-                open: None,
-            });
+            builder = builder.notify(time, Reminder::new(task.title.value(), task.content.yank_text()));
         }
-        let notifier = builder.finish();
+        let mut notifier = builder.finish();
         if let Some(dirs) = ProjectDirs::from("", "", "Rusty Notifier") {
             let data_path = dirs.data_dir();
             notifier.save(data_path.join("reminders.json"))?;
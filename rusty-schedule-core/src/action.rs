@@ -0,0 +1,42 @@
+use std::{fmt, io};
+
+use crate::FiredReminder;
+
+/// Side effect of a reminder, run once it is notified.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReminderAction {
+    /// Opens a URL in the browser, or an application.
+    Open(String),
+}
+
+impl fmt::Display for ReminderAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReminderAction::Open(open) => write!(f, "open {open}"),
+        }
+    }
+}
+
+/// Runs the actions of fired reminders, implement it to intercept, log or veto them.
+pub trait ActionExecutor {
+    fn execute(&mut self, fired: &FiredReminder<'_>, action: &ReminderAction) -> io::Result<()>;
+}
+
+/// Runs actions on the system, opening URLs and applications.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SystemExecutor;
+
+impl ActionExecutor for SystemExecutor {
+    fn execute(&mut self, _fired: &FiredReminder<'_>, action: &ReminderAction) -> io::Result<()> {
+        match action {
+            ReminderAction::Open(open) => open::that_detached(open),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("there was a problem running `{action}`: {source}")]
+pub struct ActionError {
+    pub action: ReminderAction,
+    pub source: io::Error,
+}
@@ -0,0 +1,25 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// What to do with reminders that were missed, because the app was closed or the system was
+/// suspended when they were due.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUp {
+    /// Never notify missed reminders.
+    Skip,
+    /// Notify every missed reminder.
+    FireAll,
+    /// Only notify the latest missed occurrence of each reminder.
+    FireLatestOnly,
+    /// Notify missed reminders that were due at most this long ago.
+    FireIfWithin(#[serde(with = "crate::duration")] Duration),
+}
+
+impl Default for CatchUp {
+    /// Notifies reminders missed within the last 15 minutes, so starting the app later in the day
+    /// does not notify everything due earlier at once.
+    fn default() -> Self {
+        CatchUp::FireIfWithin(Duration::minutes(15))
+    }
+}
@@ -0,0 +1,290 @@
+use std::{fmt, hash::{Hash, Hasher}, str::FromStr};
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+/// Schedule from a cron expression, such as `30 9 * * MON-FRI`.
+///
+/// Expressions have five fields, minute, hour, day of month, month and day of week, or six with
+/// seconds first. Fields take `*`, values, ranges, lists and steps such as `*/15` or `1-5/2`,
+/// and months and days of week also take names such as `JAN` and `MON`. When both the day of
+/// month and the day of week are restricted, either one matching is enough, as in crontab. The
+/// macros `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` are also understood.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(try_from = "String", into = "String")]
+pub struct CronSchedule {
+    text: String,
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CronError {
+    #[error("invalid cron expression `{0}`, expected five or six fields")]
+    FieldCount(String),
+    #[error("invalid {field} `{value}` in cron expression `{expression}`")]
+    Invalid {
+        expression: String,
+        field: &'static str,
+        value: String,
+    },
+}
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const SECOND: Field = Field { name: "second", min: 0, max: 59, names: &[] };
+const MINUTE: Field = Field { name: "minute", min: 0, max: 59, names: &[] };
+const HOUR: Field = Field { name: "hour", min: 0, max: 23, names: &[] };
+const DAY_OF_MONTH: Field = Field { name: "day of month", min: 1, max: 31, names: &[] };
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"],
+};
+/// Sunday is both `0` and `7`.
+const DAY_OF_WEEK: Field = Field {
+    name: "day of week",
+    min: 0,
+    max: 7,
+    names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+};
+
+impl Field {
+    fn parse_value(&self, value: &str) -> Option<u32> {
+        let value = match self.names.iter().position(|name| name.eq_ignore_ascii_case(value)) {
+            Some(index) => index as u32 + if self.min == 1 { 1 } else { 0 },
+            None => value.parse().ok()?,
+        };
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+    /// Bits of the values the field matches, and whether it matches every value.
+    fn parse(&self, text: &str) -> Option<(u64, bool)> {
+        let mut bits = 0;
+        for item in text.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, step.parse().ok().filter(|step| *step > 0)?),
+                None => (item, 1),
+            };
+            let (first, last) = match range {
+                "*" | "?" => (self.min, self.max),
+                _ => match range.split_once('-') {
+                    Some((first, last)) => (self.parse_value(first)?, self.parse_value(last)?),
+                    None => {
+                        let value = self.parse_value(range)?;
+                        (value, if item.contains('/') { self.max } else { value })
+                    },
+                },
+            };
+            if first > last {
+                return None;
+            }
+            for value in (first..=last).step_by(step) {
+                bits |= 1 << value;
+            }
+        }
+        Some((bits, text == "*" || text == "?"))
+    }
+}
+
+impl CronSchedule {
+    /// Whether the schedule has times on the given date.
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        let day_of_month = self.days_of_month & 1 << date.day() != 0;
+        let day_of_week = self.days_of_week & 1 << date.weekday().num_days_from_sunday() != 0;
+        let day = match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        };
+        day && self.months & 1 << date.month() != 0
+    }
+    /// Times of day of the schedule, on the dates it occurs on.
+    pub fn times(&self) -> impl Iterator<Item = NaiveTime> + '_ {
+        self.times_within(NaiveTime::MIN, NaiveTime::from_hms_opt(23, 59, 59).unwrap())
+    }
+    /// Times of day of the schedule from `first` to `last`, both inclusive. Only the hours in
+    /// between are gone through, since schedules with seconds may have a time every second.
+    pub fn times_within(&self, first: NaiveTime, last: NaiveTime) -> impl Iterator<Item = NaiveTime> + '_ {
+        let values = |bits: u64, min: u32, max: u32| (min..=max).filter(move |value| bits & 1 << value != 0);
+        values(self.hours, first.hour(), last.hour()).flat_map(move |hour| {
+            values(self.minutes, 0, 59).flat_map(move |minute| {
+                values(self.seconds, 0, 59).map(move |second| NaiveTime::from_hms_opt(hour, minute, second).unwrap())
+            })
+        })
+        .filter(move |time| (first..=last).contains(time))
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let expression = match text.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expression => expression,
+        };
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        let (second, fields) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            _ => return Err(CronError::FieldCount(text.into())),
+        };
+        let parse = |field: &Field, value: &str| field.parse(value).ok_or_else(|| CronError::Invalid {
+            expression: text.into(),
+            field: field.name,
+            value: value.into(),
+        });
+        let (days_of_month, any_day_of_month) = parse(&DAY_OF_MONTH, fields[2])?;
+        let (days_of_week, any_day_of_week) = parse(&DAY_OF_WEEK, fields[4])?;
+        Ok(CronSchedule {
+            text: text.into(),
+            seconds: parse(&SECOND, second)?.0,
+            minutes: parse(&MINUTE, fields[0])?.0,
+            hours: parse(&HOUR, fields[1])?.0,
+            days_of_month,
+            months: parse(&MONTH, fields[3])?.0,
+            // Sunday as `7` is the same as `0`.
+            days_of_week: (days_of_week | days_of_week >> 7) & 0x7F,
+            any_day_of_month,
+            any_day_of_week,
+        })
+    }
+}
+
+impl PartialEq for CronSchedule {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for CronSchedule {}
+
+impl Hash for CronSchedule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = CronError;
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<CronSchedule> for String {
+    fn from(schedule: CronSchedule) -> Self {
+        schedule.text
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+
+    fn cron(text: &str) -> CronSchedule {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn names_match_months_and_days_of_week() {
+        let schedule = cron("0 9 * JAN-mar Mon");
+        assert!(schedule.occurs_on(date(2026, 1, 5)));
+        assert!(schedule.occurs_on(date(2026, 3, 30)));
+        assert!(!schedule.occurs_on(date(2026, 1, 6)));
+        assert!(!schedule.occurs_on(date(2026, 4, 6)));
+    }
+
+    #[test]
+    fn ranges_and_steps_list_times() {
+        let times: Vec<_> = cron("*/20 9-17/4 * * *").times().collect();
+        assert_eq!(times, [9, 13, 17].into_iter()
+            .flat_map(|hour| [0, 20, 40].map(|minute| time(hour, minute, 0)))
+            .collect::<Vec<_>>());
+        let times: Vec<_> = cron("5/20,58 9 * * *").times().collect();
+        assert_eq!(times, vec![time(9, 5, 0), time(9, 25, 0), time(9, 45, 0), time(9, 58, 0)]);
+    }
+
+    #[test]
+    fn sunday_is_zero_or_seven() {
+        // 2026-01-04 is a Sunday.
+        for text in ["0 9 * * 0", "0 9 * * 7", "0 9 * * SUN", "0 9 * * 5-7"] {
+            assert!(cron(text).occurs_on(date(2026, 1, 4)), "{text}");
+            assert!(!cron(text).occurs_on(date(2026, 1, 5)), "{text}");
+        }
+        assert!(cron("0 9 * * 5-7").occurs_on(date(2026, 1, 2)));
+    }
+
+    #[test]
+    fn restricted_day_of_month_or_day_of_week_is_enough() {
+        let schedule = cron("0 9 13 * FRI");
+        assert!(schedule.occurs_on(date(2026, 1, 2)));
+        assert!(schedule.occurs_on(date(2026, 1, 13)));
+        assert!(!schedule.occurs_on(date(2026, 1, 14)));
+        let schedule = cron("0 9 13 * *");
+        assert!(schedule.occurs_on(date(2026, 1, 13)));
+        assert!(!schedule.occurs_on(date(2026, 1, 2)));
+        let schedule = cron("0 9 * * FRI");
+        assert!(schedule.occurs_on(date(2026, 1, 2)));
+        assert!(!schedule.occurs_on(date(2026, 1, 13)));
+    }
+
+    #[test]
+    fn seconds_are_first_of_six_fields() {
+        assert_eq!(cron("30 9 * * *").times().collect::<Vec<_>>(), vec![time(9, 30, 0)]);
+        assert_eq!(cron("15 30 9 * * *").times().collect::<Vec<_>>(), vec![time(9, 30, 15)]);
+        assert_eq!(cron("@daily").times().collect::<Vec<_>>(), vec![time(0, 0, 0)]);
+        assert!(matches!("30 9 * *".parse::<CronSchedule>(), Err(CronError::FieldCount(_))));
+        assert!(matches!("0 30 9 * * * *".parse::<CronSchedule>(), Err(CronError::FieldCount(_))));
+    }
+
+    #[test]
+    fn times_within_only_lists_times_in_range() {
+        let times: Vec<_> = cron("* * * * * *").times_within(time(9, 59, 59), time(10, 0, 1)).collect();
+        assert_eq!(times, vec![time(9, 59, 59), time(10, 0, 0), time(10, 0, 1)]);
+        assert_eq!(cron("* * * * * *").times_within(time(10, 0, 1), time(10, 0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn invalid_fields_are_rejected() {
+        for (text, field) in [
+            ("60 * * * *", "minute"),
+            ("* 24 * * *", "hour"),
+            ("* * 0 * *", "day of month"),
+            ("* * * FOO *", "month"),
+            ("* * * * 8", "day of week"),
+            ("5-1 * * * *", "minute"),
+            ("*/0 * * * *", "minute"),
+        ] {
+            assert!(matches!(text.parse::<CronSchedule>(), Err(CronError::Invalid { field: invalid, .. }) if invalid == field), "{text}");
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::Reminder;
+
+/// Reminder due at a date and time, which alerts ahead of it as well as when it is due.
+#[derive(Serialize, Deserialize)]
+pub struct Deadline {
+    #[serde(flatten)]
+    pub reminder: Reminder,
+    /// How long before the deadline to alert, such as `["7d", "1d", "1h"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "crate::duration::list")]
+    pub lead: Vec<Duration>,
+}
+
+impl Deadline {
+    pub fn new(reminder: Reminder, lead: Vec<Duration>) -> Self {
+        Deadline {
+            reminder,
+            lead,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Zone;
+
+/// How a reminder is notified on days its local time is skipped or repeated, because the clocks
+/// change for daylight saving time.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DstPolicy {
+    /// Local times skipped when the clocks spring forward.
+    #[serde(default)]
+    pub nonexistent: NonexistentTime,
+    /// Local times repeated when the clocks fall back.
+    #[serde(default)]
+    pub ambiguous: AmbiguousTime,
+}
+
+/// What to do with a local time skipped when the clocks spring forward.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NonexistentTime {
+    /// Notify as much later as the clocks moved, so 02:30 becomes 03:30 when skipping an hour.
+    #[default]
+    ShiftForward,
+    /// Do not notify on that day.
+    Skip,
+}
+
+/// What to do with a local time repeated when the clocks fall back.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AmbiguousTime {
+    /// Notify once, the first time the local time comes around.
+    #[default]
+    Earliest,
+    /// Notify once, the second time the local time comes around.
+    Latest,
+}
+
+impl DstPolicy {
+    /// Instant to notify at the local date and time of the zone, if any.
+    pub fn resolve(&self, zone: Zone, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match zone.instant(local) {
+            LocalResult::Single(instant) => Some(instant),
+            LocalResult::Ambiguous(earliest, latest) => match self.ambiguous {
+                AmbiguousTime::Earliest => Some(earliest),
+                AmbiguousTime::Latest => Some(latest),
+            },
+            LocalResult::None => match self.nonexistent {
+                NonexistentTime::ShiftForward => {
+                    // Keep the offset from before the clocks moved, which lands past the gap.
+                    let before = local - Duration::days(1);
+                    let offset = before - zone.instant(before).earliest()?.naive_utc();
+                    Some((local - offset).and_utc())
+                },
+                NonexistentTime::Skip => None,
+            },
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Serde support for durations written as `45s`, `15m`, `1h30m` or `7d`.
+
+use chrono::Duration;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+const UNITS: [(char, i64); 4] = [
+    ('d', 24 * 60 * 60),
+    ('h', 60 * 60),
+    ('m', 60),
+    ('s', 1),
+];
+
+/// Parses a duration such as `1h30m`, optionally prefixed with `-`.
+pub(crate) fn parse(text: &str) -> Option<Duration> {
+    let (negative, mut rest) = match text.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.trim()),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    let mut seconds = 0i64;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let value: i64 = rest[..digits].parse().ok()?;
+        let unit = rest[digits..].chars().next()?;
+        let (_, scale) = UNITS.iter().find(|(name, _)| *name == unit)?;
+        seconds = seconds.checked_add(value.checked_mul(*scale)?)?;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+    Duration::try_seconds(if negative { -seconds } else { seconds })
+}
+
+/// Formats a duration the way [`parse`] reads it, such as `1h30m`.
+pub(crate) fn format(duration: Duration) -> String {
+    let mut seconds = duration.num_seconds();
+    if seconds == 0 {
+        return "0s".into();
+    }
+    let mut text = String::new();
+    if seconds < 0 {
+        text.push('-');
+        seconds = -seconds;
+    }
+    for (name, scale) in UNITS {
+        if seconds >= scale {
+            text.push_str(&format!("{}{name}", seconds / scale));
+            seconds %= scale;
+        }
+    }
+    text
+}
+
+pub(crate) fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format(*duration))
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    parse(&text).ok_or_else(|| de::Error::custom(format!("invalid duration `{text}`, expected something like `15m`, `1h30m` or `7d`")))
+}
+
+/// Durations that must be longer than zero.
+pub(crate) mod positive {
+    use chrono::Duration;
+    use serde::{de, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize(duration, serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let duration = super::deserialize(deserializer)?;
+        if duration <= Duration::zero() {
+            return Err(de::Error::custom(format!("duration `{}` must be longer than zero", super::format(duration))));
+        }
+        Ok(duration)
+    }
+}
+
+/// Describes a duration for people, such as `1 day` or `2 hours 30 minutes`.
+pub(crate) fn describe(duration: Duration) -> String {
+    const NAMES: [(&str, i64); 4] = [
+        ("day", 24 * 60 * 60),
+        ("hour", 60 * 60),
+        ("minute", 60),
+        ("second", 1),
+    ];
+    let mut seconds = duration.num_seconds().abs();
+    let mut parts = Vec::new();
+    for (name, scale) in NAMES {
+        if seconds >= scale {
+            let value = seconds / scale;
+            parts.push(format!("{value} {name}{}", if value == 1 { "" } else { "s" }));
+            seconds %= scale;
+        }
+    }
+    if parts.is_empty() {
+        return "0 seconds".into();
+    }
+    parts.join(" ")
+}
+
+/// Lists of durations.
+pub(crate) mod list {
+    use chrono::Duration;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(durations: &[Duration], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(durations.iter().map(|duration| super::format(*duration)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Duration>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?.into_iter()
+            .map(|text| super::parse(&text).ok_or_else(|| de::Error::custom(format!("invalid duration `{text}`, expected something like `15m`, `1h30m` or `7d`"))))
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+use std::num::NonZeroU32;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::OccurrenceStatus;
+
+/// Notifies a reminder again and again until it is acknowledged, louder after a number of
+/// attempts.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Escalation {
+    /// How long to wait for an acknowledgement before notifying again.
+    #[serde(with = "crate::duration::positive")]
+    pub every: Duration,
+    /// Attempts after which notifications are escalated, never when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<NonZeroU32>,
+    /// Attempts after which the reminder is no longer notified again, until acknowledged when not
+    /// set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<NonZeroU32>,
+    /// Urgency of escalated notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub urgency: Option<Urgency>,
+    /// Channel escalated notifications are also delivered through.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Urgency {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+/// How a notification is delivered.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Channel {
+    /// Desktop notification.
+    #[default]
+    Notification,
+    /// Line printed to the console, ringing its bell.
+    Console,
+    /// Application, file or URL to open, such as an alarm sound.
+    Open(String),
+}
+
+impl Escalation {
+    /// Whether the attempt is escalated.
+    pub fn escalates(&self, attempt: u32) -> bool {
+        self.after.is_some_and(|after| attempt > after.get())
+    }
+    /// Status of an occurrence after notifying it for the attempt at `now`, which stops
+    /// escalating once it was the last attempt.
+    pub(crate) fn status(&self, attempt: u32, now: DateTime<Utc>) -> OccurrenceStatus {
+        if self.max_attempts.is_some_and(|max_attempts| attempt >= max_attempts.get()) {
+            return OccurrenceStatus::Fired;
+        }
+        OccurrenceStatus::Escalating {
+            attempt,
+            until: now + self.every,
+        }
+    }
+}
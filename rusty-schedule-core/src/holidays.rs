@@ -0,0 +1,211 @@
+use std::{collections::HashSet, fs, io, path::Path, str::FromStr};
+
+use chrono::{Datelike, Days, NaiveDate, NaiveTime, Weekday};
+
+use crate::{rrule::parse_date_time, RRule, RRuleError};
+
+/// Public holidays, on which reminders limited to business days do not notify.
+///
+/// Loaded from a file of dates, one `YYYY-MM-DD` per line with `#` starting a comment, or from an
+/// iCalendar `.ics` file whose events are holidays. Events span from `DTSTART` until `DTEND`,
+/// and repeat by their `RRULE` on the start date only.
+#[derive(Clone, Default, Debug)]
+pub struct HolidayCalendar {
+    dates: HashSet<NaiveDate>,
+    rules: Vec<RRule>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HolidayCalendarError {
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    #[error("invalid date `{text}` on line {line}")]
+    InvalidDate {
+        line: usize,
+        text: String,
+    },
+    #[error(transparent)]
+    RRule(#[from] RRuleError),
+}
+
+impl HolidayCalendar {
+    /// Loads the holidays at `path`, as iCalendar when it ends with `.ics`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HolidayCalendarError> {
+        let text = fs::read_to_string(path.as_ref())?;
+        if path.as_ref().extension().is_some_and(|extension| extension.eq_ignore_ascii_case("ics")) {
+            HolidayCalendar::from_ics(&text)
+        } else {
+            text.parse()
+        }
+    }
+    /// Parses the events of an iCalendar file as holidays.
+    pub fn from_ics(text: &str) -> Result<Self, HolidayCalendarError> {
+        let mut calendar = HolidayCalendar::default();
+        let mut event: Option<Vec<(usize, String)>> = None;
+        for (line, content) in unfold(text) {
+            match content.as_str() {
+                "BEGIN:VEVENT" => event = Some(Vec::new()),
+                "END:VEVENT" => if let Some(properties) = event.take() {
+                    calendar.add_event(&properties)?;
+                },
+                _ => if let Some(ref mut properties) = event {
+                    properties.push((line, content));
+                },
+            }
+        }
+        Ok(calendar)
+    }
+    fn add_event(&mut self, properties: &[(usize, String)]) -> Result<(), HolidayCalendarError> {
+        let mut start = None;
+        let mut end = None;
+        let mut rule = Vec::new();
+        for (line, content) in properties {
+            let Some((name, value)) = content.split_once(':') else { continue };
+            let date = || parse_date_time(value)
+                .map(|date| date.date())
+                .ok_or_else(|| HolidayCalendarError::InvalidDate { line: *line, text: value.into() });
+            match name.split(';').next().unwrap() {
+                "DTSTART" => {
+                    start = Some(date()?);
+                    rule.push(content.as_str());
+                },
+                "DTEND" => end = Some(date()?),
+                "RRULE" | "EXDATE" => rule.push(content.as_str()),
+                _ => {},
+            }
+        }
+        let Some(start) = start else { return Ok(()) };
+        if rule.iter().any(|property| property.starts_with("RRULE")) {
+            self.rules.push(rule.join("\n").parse()?);
+        } else {
+            let end = end.filter(|end| *end > start).unwrap_or(start + Days::new(1));
+            self.dates.extend(start.iter_days().take_while(|date| *date < end));
+        }
+        Ok(())
+    }
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        // Holidays are whole days, compared with an `UNTIL` in UTC as though they were in UTC.
+        let start = date.and_time(NaiveTime::MIN);
+        self.dates.contains(&date) || self.rules.iter().any(|rule| rule.occurs_on(date) && rule.allows(start, start.and_utc()))
+    }
+    /// Whether the date is a weekday other than a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.is_holiday(date)
+    }
+}
+
+/// Lines of an iCalendar file, numbered from 1, with folded lines joined back together.
+fn unfold(text: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(folded), Some((_, previous))) => previous.push_str(folded),
+            _ => lines.push((index + 1, line.trim_end().to_owned())),
+        }
+    }
+    lines
+}
+
+impl FromStr for HolidayCalendar {
+    type Err = HolidayCalendarError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut calendar = HolidayCalendar::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let date = NaiveDate::from_str(line)
+                .map_err(|_| HolidayCalendarError::InvalidDate { line: index + 1, text: line.into() })?;
+            calendar.dates.insert(date);
+        }
+        Ok(calendar)
+    }
+}
+
+impl FromIterator<NaiveDate> for HolidayCalendar {
+    fn from_iter<T: IntoIterator<Item = NaiveDate>>(dates: T) -> Self {
+        HolidayCalendar {
+            dates: dates.into_iter().collect(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn folded_lines_are_joined() {
+        let calendar = HolidayCalendar::from_ics(concat!(
+            "BEGIN:VCALENDAR\r\n",
+            "BEGIN:VEVENT\r\n",
+            "SUMMARY:Christmas\r\n",
+            "DTSTART;VALUE=DATE:20261225\r\n",
+            "RRULE:FREQ=YEARLY;BYMONTH=12;BY\r\n",
+            " MONTHDAY=25\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+        )).unwrap();
+        assert!(calendar.is_holiday(date(2026, 12, 25)));
+        assert!(calendar.is_holiday(date(2027, 12, 25)));
+        assert!(!calendar.is_holiday(date(2026, 12, 26)));
+    }
+
+    #[test]
+    fn all_day_events_end_before_dtend() {
+        let calendar = HolidayCalendar::from_ics(concat!(
+            "BEGIN:VEVENT\n",
+            "DTSTART;VALUE=DATE:20261224\n",
+            "DTEND;VALUE=DATE:20261227\n",
+            "END:VEVENT\n",
+            "BEGIN:VEVENT\n",
+            "DTSTART;VALUE=DATE:20261231\n",
+            "END:VEVENT\n",
+        )).unwrap();
+        assert!(!calendar.is_holiday(date(2026, 12, 23)));
+        assert!(calendar.is_holiday(date(2026, 12, 24)));
+        assert!(calendar.is_holiday(date(2026, 12, 26)));
+        assert!(!calendar.is_holiday(date(2026, 12, 27)));
+        // Without a `DTEND`, an event lasts its start date.
+        assert!(calendar.is_holiday(date(2026, 12, 31)));
+        assert!(!calendar.is_holiday(date(2027, 1, 1)));
+    }
+
+    #[test]
+    fn yearly_rule_repeats_from_its_start() {
+        let calendar = HolidayCalendar::from_ics(concat!(
+            "BEGIN:VEVENT\n",
+            "DTSTART;VALUE=DATE:20260501\n",
+            "RRULE:FREQ=YEARLY\n",
+            "END:VEVENT\n",
+        )).unwrap();
+        assert!(!calendar.is_holiday(date(2025, 5, 1)));
+        assert!(calendar.is_holiday(date(2026, 5, 1)));
+        assert!(calendar.is_holiday(date(2030, 5, 1)));
+        assert!(!calendar.is_holiday(date(2030, 5, 2)));
+    }
+
+    #[test]
+    fn date_list_skips_comments_and_rejects_bad_lines() {
+        let calendar: HolidayCalendar = "# Public holidays\n2026-05-01\n\n2026-12-25 # Christmas\n".parse().unwrap();
+        assert!(calendar.is_holiday(date(2026, 5, 1)));
+        assert!(calendar.is_holiday(date(2026, 12, 25)));
+        assert!(!calendar.is_holiday(date(2026, 12, 24)));
+        let error = "2026-05-01\n2026-13-01\n".parse::<HolidayCalendar>().unwrap_err();
+        assert!(matches!(error, HolidayCalendarError::InvalidDate { line: 2, ref text } if text == "2026-13-01"));
+    }
+
+    #[test]
+    fn business_days_skip_weekends_and_holidays() {
+        let calendar: HolidayCalendar = [date(2026, 5, 1)].into_iter().collect();
+        assert!(!calendar.is_business_day(date(2026, 5, 1)));
+        assert!(!calendar.is_business_day(date(2026, 5, 2)));
+        assert!(calendar.is_business_day(date(2026, 5, 4)));
+    }
+}
@@ -1,16 +1,89 @@
-use std::{collections::{hash_map::Entry, HashMap}, fs::File, io::{self, Read, Write}, path::Path, time::SystemTime};
+use std::{collections::{hash_map::Entry, HashSet}, convert::Infallible, fmt, fs::File, io::{self, Read, Write}, path::{Path, PathBuf}, str::FromStr};
 
-use chrono::{Datelike, Local, NaiveTime, Timelike, Weekday};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+pub use action::{ActionError, ActionExecutor, ReminderAction, SystemExecutor};
+pub use catch_up::CatchUp;
+pub use clock::{Clock, SystemClock};
+pub use cron::{CronError, CronSchedule};
+pub use deadline::Deadline;
+pub use dst::{AmbiguousTime, DstPolicy, NonexistentTime};
+pub use escalation::{Channel, Escalation, Urgency};
+pub use holidays::{HolidayCalendar, HolidayCalendarError};
+pub use occurrence::{FiredReminder, Occurrence, OccurrenceOverride, ReminderCheck};
+pub use recurrence::{Recurrence, RecurrenceError};
+pub use repeat::Repeat;
+pub use quota::{Quota, QuotaPeriod, QuotaProgress};
+pub use routine::{Routine, RoutineStep};
+pub use rrule::{RRule, RRuleError};
+pub use spaced::{ReviewProgress, SpacedRepetition};
+pub use state::{OccurrenceKey, OccurrenceStatus};
+pub use stats::{Completion, Outcome, Streak, WeekCompletion};
+pub use zone::{TravelMode, Zone};
+
+use schedule::Schedule;
+use state::NotifierState;
+
+mod action;
+mod catch_up;
+mod clock;
+mod cron;
+mod deadline;
+mod dst;
+mod duration;
+mod escalation;
+mod holidays;
+mod occurrence;
+mod quota;
+mod recurrence;
+mod repeat;
+mod routine;
+mod rrule;
+mod schedule;
+mod spaced;
+mod state;
+mod stats;
+mod template;
+pub mod testing;
+mod zone;
+
+/// How long past its scheduled time an occurrence is considered missed rather than due.
+pub const MISSED_AFTER: Duration = Duration::minutes(1);
+/// How far back missed occurrences are looked for when first checking for reminders, or when
+/// checking again after a long time, such as after the app was stopped.
+pub const CATCH_UP_HORIZON: Duration = Duration::days(1);
+/// How far ahead upcoming occurrences are looked for.
+pub const QUERY_HORIZON: Duration = Duration::days(8 * 366);
 
 /// Notifies use of reminders.
 #[derive(Serialize, Deserialize)]
 pub struct Notifier {
-    reminders: HashMap<NaiveTime, Vec<Reminder>>,
-    /// Latest reminder that was notified.
+    #[serde(flatten)]
+    schedule: Schedule,
+    /// Lifecycle of occurrences, saved separately from the reminders.
     #[serde(skip)]
-    latest_notified: Option<NaiveTime>,
+    state: NotifierState,
+    #[serde(skip, default = "system_clock")]
+    clock: Box<dyn Clock>,
+}
+
+fn system_clock() -> Box<dyn Clock> {
+    Box::new(SystemClock)
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("no reminder with id {0}")]
+pub struct UnknownReminder(pub ReminderId);
+
+#[derive(thiserror::Error, Debug)]
+pub enum SnoozeError {
+    #[error(transparent)]
+    UnknownReminder(#[from] UnknownReminder),
+    #[error("reminder with id {0} was not notified yet")]
+    NotNotified(ReminderId),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -18,83 +91,308 @@ pub enum NotifierLoadError {
     #[error(transparent)]
     IO(#[from] io::Error),
     #[error(transparent)]
-    Serde(#[from] serde_json::Error)
+    Serde(#[from] serde_json::Error),
+    #[error("holidays: {0}")]
+    Holidays(#[from] HolidayCalendarError),
 }
 
 impl Notifier {
+    /// Loads the reminders at `path`, along with their state if it was saved.
+    ///
+    /// Reminders saved without an identifier, or sharing one with another reminder, are given a
+    /// new one, and the reminders are written back to `path` so it stays stable. Nothing else is
+    /// changed, expired reminders are only pruned by [`Notifier::save`].
     pub fn load(path: impl AsRef<Path>) -> Result<Self, NotifierLoadError> {
-        let mut file = File::open(path)?;
+        let mut file = File::open(path.as_ref())?;
         let mut read = Vec::default();
         file.read_to_end(&mut read)?;
-        let notifier = serde_json::de::from_slice(&read)?;
+        let mut notifier: Notifier = serde_json::de::from_slice(&read)?;
+        if let Some(ref holidays) = notifier.schedule.holidays_path {
+            // Relative to the directory of the reminders.
+            let holidays = path.as_ref().parent().unwrap_or(Path::new("")).join(holidays);
+            notifier.schedule.holidays = HolidayCalendar::load(holidays)?;
+        }
+        notifier.state = NotifierState::load(Notifier::state_path(&path))?;
+        if notifier.assign_ids() {
+            notifier.write(&path)?;
+        }
         Ok(notifier)
     }
-    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+    /// Gives reminders without a unique identifier a new one, returns whether any were given.
+    fn assign_ids(&mut self) -> bool {
+        let mut assigned = false;
+        let mut ids = HashSet::new();
+        for reminder in self.schedule.reminders_mut() {
+            if reminder.id.is_unassigned() || !ids.insert(reminder.id.clone()) {
+                reminder.id = ReminderId::new();
+                ids.insert(reminder.id.clone());
+                assigned = true;
+            }
+        }
+        assigned
+    }
+    /// Saves the reminders at `path`, after pruning expired reminders when
+    /// [`NotifierBuilder::prune_expired`] is set.
+    pub fn save(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        if self.schedule.prune_expired {
+            self.prune_expired();
+        }
+        self.write(path)
+    }
+    fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
         let mut file = File::create(path)?;
         let saved_data = serde_json::ser::to_vec(self).unwrap();
         file.write_all(&saved_data)?;
         Ok(())
     }
-    /// Iterator of reminders yet to be notified.
-    pub fn check_reminders(&mut self) -> impl Iterator<Item = &Reminder> {
-        let date = Local::now().naive_local();
-        let time = date.time();
-        let weekday = date.weekday();
-        let latest_notified = self.latest_notified.clone();
-        let reminders = self.reminders.iter()
-            .filter_map(move |(reminder_time, reminder)| {
-                if let Some(latest_notified) = latest_notified {
-                    if *reminder_time > latest_notified && *reminder_time <= time {
-                        Some(reminder)
-                    } else {
-                        None
-                    }
-                } else {
-                    if *reminder_time <= time {
-                        Some(reminder)
-                    } else {
-                        None
-                    }
-                }
-            })
-            .flatten()
-            .filter(move |reminder| {
-                if let Some(ref weekdays) = reminder.weekdays {
-                    weekdays.contains(&weekday)
-                } else {
-                    true
-                }
-            })
-            .inspect(|reminder| {
-                if let Some(ref open) = reminder.open {
-                    let execute_open = |open: &str| {
-                        if let Err(e) = open::that_detached(open) {
-                            println!("There was a problem opening: {open}—{e}");
-                        }
-                    };
-                    match open {
-                        ReminderOpen::Single(open) => execute_open(open),
-                        ReminderOpen::Multiple(open) => {
-                            for open in open {
-                                execute_open(open);
-                            }
-                        },
-                    }
-                }
-            });
-        self.latest_notified = Some(time);
-        reminders
+    /// Saves the state of the reminders at `path` next to them.
+    pub fn save_state(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.state.save(Notifier::state_path(path))
+    }
+    /// Path of the state of the reminders at `path`, such as `reminders.state.json` for `reminders.json`.
+    pub fn state_path(path: impl AsRef<Path>) -> PathBuf {
+        path.as_ref().with_extension("state.json")
+    }
+    /// Replaces the clock used to tell the time, which is the system clock by default.
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.clock = Box::new(clock);
+    }
+    /// Current date and time in the time zone of the system, according to the clock of the notifier.
+    pub fn now(&self) -> DateTime<Local> {
+        self.clock.now().with_timezone(&Local)
+    }
+    /// Time zone the reminder is scheduled in.
+    pub fn zone(&self, reminder: &Reminder) -> Zone {
+        self.schedule.zone(reminder)
+    }
+    pub fn get(&self, id: &ReminderId) -> Option<&Reminder> {
+        self.schedule.get(id)
+    }
+    pub fn get_mut(&mut self, id: &ReminderId) -> Option<&mut Reminder> {
+        self.schedule.reminders_mut().find(|reminder| reminder.id == *id)
+    }
+    /// Replaces the reminder with the given identifier, which the new reminder takes on.
+    /// Returns the replaced reminder.
+    pub fn update(&mut self, id: &ReminderId, mut reminder: Reminder) -> Option<Reminder> {
+        let current = self.get_mut(id)?;
+        reminder.id = id.clone();
+        Some(std::mem::replace(current, reminder))
+    }
+    /// Removes the reminder with the given identifier, along with its state.
+    pub fn remove(&mut self, id: &ReminderId) -> Option<Reminder> {
+        let reminder = self.schedule.remove(id)?;
+        self.state.forget_reminder(id);
+        Some(reminder)
+    }
+    /// Removes reminders whose `until` date has passed, along with their state.
+    pub fn prune_expired(&mut self) -> Vec<Reminder> {
+        let now = self.clock.now();
+        let expired: Vec<_> = self.schedule.reminders()
+            .filter(|reminder| reminder.expired_on(self.schedule.zone(reminder).local(now).date()))
+            .map(|reminder| reminder.id.clone())
+            .collect();
+        expired.iter().filter_map(|id| self.remove(id)).collect()
+    }
+    /// Routines, whose steps are among the reminders.
+    pub fn routines(&self) -> &[Routine] {
+        &self.schedule.routines
+    }
+    /// Routine with the given title, to move its anchor, or enable or disable it.
+    pub fn routine_mut(&mut self, title: &str) -> Option<&mut Routine> {
+        self.schedule.routines.iter_mut().find(|routine| routine.title == title)
+    }
+    /// Iterator of all reminders.
+    pub fn reminders(&self) -> impl Iterator<Item = &Reminder> {
+        self.schedule.reminders()
+    }
+    /// Lifecycle status of an occurrence.
+    pub fn status(&self, key: &OccurrenceKey) -> OccurrenceStatus {
+        self.state.status(key)
+    }
+    /// Marks an occurrence as acknowledged, so it is not notified again.
+    ///
+    /// The occurrence is logged as done, spaced repetition reminders move up their ladder, and
+    /// quota reminders count it.
+    pub fn acknowledge(&mut self, key: &OccurrenceKey) {
+        if self.state.status(key) == OccurrenceStatus::Acknowledged {
+            return;
+        }
+        self.state.set_status(key.clone(), OccurrenceStatus::Acknowledged);
+        self.state.log(Completion {
+            key: key.clone(),
+            outcome: Outcome::Done,
+            at: self.clock.now(),
+        });
+        let Some(reminder) = self.schedule.get(&key.reminder) else { return };
+        if let Some(ref spaced) = reminder.spaced {
+            let progress = spaced.advance(self.state.review(&key.reminder), key.scheduled.date());
+            self.state.set_review(key.reminder.clone(), progress);
+        }
+        if let Some(quota) = reminder.quota {
+            let progress = quota.count(self.state.quota(&key.reminder), key.scheduled.date());
+            self.state.set_quota(key.reminder.clone(), progress);
+        }
+    }
+    /// Marks an occurrence as failed, so it is not notified again.
+    ///
+    /// The occurrence is logged as failed, and spaced repetition reminders start their ladder over.
+    pub fn fail(&mut self, key: &OccurrenceKey) {
+        self.state.set_status(key.clone(), OccurrenceStatus::Failed);
+        self.state.log(Completion {
+            key: key.clone(),
+            outcome: Outcome::Failed,
+            at: self.clock.now(),
+        });
+        if let Some(spaced) = self.schedule.get(&key.reminder).and_then(|reminder| reminder.spaced.as_ref()) {
+            self.state.set_review(key.reminder.clone(), spaced.reset(key.scheduled.date()));
+        }
+    }
+    /// How many more times a quota reminder is to be done in the current period.
+    pub fn quota_remaining(&self, id: &ReminderId) -> Option<u32> {
+        let reminder = self.schedule.get(id)?;
+        let today = self.schedule.zone(reminder).local(self.clock.now()).date();
+        reminder.quota.map(|quota| quota.remaining(today, self.state.quota(id)))
+    }
+    /// Progress of a spaced repetition reminder along its ladder, `None` before its first review.
+    pub fn review_progress(&self, id: &ReminderId) -> Option<ReviewProgress> {
+        self.state.review(id)
+    }
+    /// Occurrences due from `start` until `end`, in chronological order.
+    pub fn occurrences_between<T: TimeZone>(&self, start: DateTime<T>, end: DateTime<T>) -> Vec<Occurrence<'_>> {
+        if end <= start {
+            return Vec::new();
+        }
+        self.schedule.occurrences_within(start.to_utc(), end.to_utc() - Duration::nanoseconds(1), &self.state)
+    }
+    /// Next `n` occurrences yet to be notified, in chronological order.
+    ///
+    /// Occurrences already acknowledged ahead of time are left out, and occurrences further out
+    /// than [`QUERY_HORIZON`] are not looked for.
+    pub fn upcoming(&self, n: usize) -> Vec<Occurrence<'_>> {
+        let now = self.clock.now();
+        let mut upcoming = Vec::new();
+        let mut first = now + Duration::nanoseconds(1);
+        // Windows start short, since schedules with seconds may have many occurrences.
+        let mut window = Duration::hours(1);
+        while upcoming.len() < n && first - now < QUERY_HORIZON {
+            let last = first + window - Duration::nanoseconds(1);
+            window = (window * 2).min(Duration::weeks(1));
+            upcoming.extend(
+                self.schedule.occurrences_within(first, last, &self.state).into_iter()
+                    .filter(|occurrence| self.state.status(&occurrence.key()) == OccurrenceStatus::Pending)
+            );
+            first = last + Duration::nanoseconds(1);
+        }
+        upcoming.truncate(n);
+        upcoming
+    }
+    /// Next occurrence yet to be notified.
+    pub fn next_due(&self) -> Option<Occurrence<'_>> {
+        self.upcoming(1).into_iter().next()
+    }
+    /// When [`Notifier::check_reminders`] next has a reminder to notify, either an occurrence
+    /// becoming due, a snooze ending, or an escalating occurrence to notify again.
+    pub fn next_check(&self) -> Option<DateTime<Local>> {
+        let next_due = self.next_due().map(|occurrence| occurrence.at);
+        let next_renotify = self.state.next_renotify();
+        next_due.into_iter().chain(next_renotify).min().map(|next| next.with_timezone(&Local))
+    }
+    /// Notifies the latest notified occurrence of the reminder again after `duration`.
+    pub fn snooze(&mut self, id: &ReminderId, duration: Duration) -> Result<(), SnoozeError> {
+        self.snooze_until(id, self.clock.now() + duration)
+    }
+    /// Notifies the latest notified occurrence of the reminder again at `until`.
+    pub fn snooze_until<T: TimeZone>(&mut self, id: &ReminderId, until: DateTime<T>) -> Result<(), SnoozeError> {
+        if self.schedule.get(id).is_none() {
+            return Err(UnknownReminder(id.clone()).into());
+        }
+        let key = self.state.latest_notified(id).ok_or_else(|| SnoozeError::NotNotified(id.clone()))?;
+        self.snooze_occurrence(&key, until);
+        Ok(())
+    }
+    /// Notifies the occurrence again at `until`.
+    pub fn snooze_occurrence<T: TimeZone>(&mut self, key: &OccurrenceKey, until: DateTime<T>) {
+        self.state.set_status(key.clone(), OccurrenceStatus::Snoozed { until: until.to_utc() });
+    }
+    /// Reminders yet to be notified.
+    ///
+    /// Every occurrence scheduled after the previous check, up to and including now, is notified
+    /// exactly once, even when checks span multiple days or restarts, or the clock is set back.
+    /// Occurrences before [`CATCH_UP_HORIZON`] are not looked for.
+    /// Local times skipped or repeated by daylight saving time follow the [`DstPolicy`] of the
+    /// reminder. Occurrences that are overdue by more than [`MISSED_AFTER`], such as those due
+    /// before startup or during a system suspend, follow their [`CatchUp`] policy, and are
+    /// reported by [`ReminderCheck::missed`] when not notified. Snoozed occurrences are notified
+    /// again once their snooze ends, and occurrences of reminders with an [`Escalation`] are
+    /// notified again until they are acknowledged.
+    ///
+    /// Checking has no side effects besides updating the state, the actions of fired reminders
+    /// are run with [`FiredReminder::run_actions`].
+    pub fn check_reminders(&mut self) -> ReminderCheck<'_> {
+        let now = self.clock.now();
+        let mut check = ReminderCheck::default();
+        for (key, attempt) in self.state.renotify_until(now) {
+            let Some(reminder) = self.schedule.get(&key.reminder) else {
+                self.state.set_status(key, OccurrenceStatus::Fired);
+                continue;
+            };
+            self.state.set_status(key.clone(), notified(reminder, attempt, now));
+            if let Some(occurrence) = self.schedule.occurrence(reminder, key.scheduled, &self.state) {
+                check.fired.push(FiredReminder { attempt, ..FiredReminder::new(occurrence) });
+            }
+        }
+        let first = match self.state.last_checked {
+            Some(last_checked) if now == last_checked => return check,
+            Some(last_checked) if now < last_checked => {
+                // The clock went backwards, carry on from now, occurrences notified since are
+                // recorded and not notified again.
+                self.state.last_checked = Some(now);
+                return check;
+            },
+            // Occurrences long past, such as while the app was stopped for weeks, are left out.
+            Some(last_checked) => (last_checked + Duration::nanoseconds(1)).max(now - CATCH_UP_HORIZON),
+            None => now - CATCH_UP_HORIZON,
+        };
+        self.state.last_checked = Some(now);
+        self.state.prune();
+        let occurrences: Vec<_> = self.schedule.occurrences_within(first, now, &self.state).into_iter()
+            .filter(|occurrence| self.state.status(&occurrence.key()) == OccurrenceStatus::Pending)
+            .collect();
+        for (index, occurrence) in occurrences.iter().enumerate() {
+            let overdue = now - occurrence.at;
+            let notify = overdue <= MISSED_AFTER || match self.schedule.catch_up(occurrence.reminder) {
+                CatchUp::Skip => false,
+                CatchUp::FireAll => true,
+                CatchUp::FireLatestOnly => !occurrences[index + 1..].iter()
+                    .any(|later| later.reminder.id == occurrence.reminder.id),
+                CatchUp::FireIfWithin(within) => overdue <= within,
+            };
+            if notify {
+                self.state.set_status(occurrence.key(), notified(occurrence.reminder, 1, now));
+                check.fired.push(FiredReminder::new(*occurrence));
+            } else {
+                self.state.set_status(occurrence.key(), OccurrenceStatus::Missed);
+                check.missed.push(*occurrence);
+            }
+        }
+        check
     }
 }
 
+/// Status of an occurrence of the reminder after notifying it for the attempt at `now`.
+fn notified(reminder: &Reminder, attempt: u32, now: DateTime<Utc>) -> OccurrenceStatus {
+    reminder.escalation.as_ref().map_or(OccurrenceStatus::Fired, |escalation| escalation.status(attempt, now))
+}
+
 #[derive(Default)]
 pub struct NotifierBuilder {
-    reminders: HashMap<NaiveTime, Vec<Reminder>>,
+    schedule: Schedule,
+    clock: Option<Box<dyn Clock>>,
 }
 
 impl NotifierBuilder {
     pub fn notify(mut self, time: NaiveTime, reminder: Reminder) -> Self {
-        match self.reminders.entry(time) {
+        match self.schedule.reminders.entry(time) {
             Entry::Occupied(e) => e.into_mut().push(reminder),
             Entry::Vacant(e) => {
                 e.insert(vec![reminder]);
@@ -102,21 +400,236 @@ impl NotifierBuilder {
         }
         self
     }
+    /// Notify only once, on the given date and time.
+    pub fn notify_once(mut self, date: NaiveDateTime, reminder: Reminder) -> Self {
+        match self.schedule.once.entry(date) {
+            Entry::Occupied(e) => e.into_mut().push(reminder),
+            Entry::Vacant(e) => {
+                e.insert(vec![reminder]);
+            },
+        }
+        self
+    }
+    /// Notify when the deadline is due, and `lead` ahead of it, such as a day before.
+    pub fn notify_deadline(mut self, due: NaiveDateTime, lead: Vec<Duration>, reminder: Reminder) -> Self {
+        match self.schedule.deadlines.entry(due) {
+            Entry::Occupied(e) => e.into_mut().push(Deadline::new(reminder, lead)),
+            Entry::Vacant(e) => {
+                e.insert(vec![Deadline::new(reminder, lead)]);
+            },
+        }
+        self
+    }
+    /// Notify the steps of the routine at their offsets from its anchor.
+    pub fn routine(mut self, routine: Routine) -> Self {
+        self.schedule.routines.push(routine);
+        self
+    }
+    /// Notify at the times of a cron expression.
+    pub fn notify_cron(mut self, schedule: CronSchedule, reminder: Reminder) -> Self {
+        match self.schedule.cron.entry(schedule) {
+            Entry::Occupied(e) => e.into_mut().push(reminder),
+            Entry::Vacant(e) => {
+                e.insert(vec![reminder]);
+            },
+        }
+        self
+    }
+    /// Catch-up policy for reminders without their own.
+    pub fn catch_up(mut self, catch_up: CatchUp) -> Self {
+        self.schedule.catch_up = Some(catch_up);
+        self
+    }
+    /// Home time zone of reminders without their own, the time zone of the system by default.
+    pub fn time_zone(mut self, time_zone: Tz) -> Self {
+        self.schedule.time_zone = Some(time_zone);
+        self
+    }
+    /// Whether reminders without their own time zone follow the system when it leaves home.
+    pub fn travel_mode(mut self, travel_mode: TravelMode) -> Self {
+        self.schedule.travel_mode = Some(travel_mode);
+        self
+    }
+    /// Holidays that reminders limited to business days do not notify on.
+    pub fn holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.schedule.holidays = holidays;
+        self
+    }
+    /// Whether reminders whose `until` date has passed are removed when saving.
+    pub fn prune_expired(mut self, prune_expired: bool) -> Self {
+        self.schedule.prune_expired = prune_expired;
+        self
+    }
+    /// Clock used to tell the time, which is the system clock by default.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Box::new(clock));
+        self
+    }
     pub fn finish(self) -> Notifier {
         Notifier {
-            reminders: self.reminders,
-            latest_notified: None,
+            schedule: self.schedule,
+            state: NotifierState::default(),
+            clock: self.clock.unwrap_or_else(system_clock),
         }
     }
 }
 
+/// Stable identifier of a reminder, generated when it is created.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+#[serde(transparent)]
+pub struct ReminderId(String);
+
+impl ReminderId {
+    pub fn new() -> Self {
+        ReminderId(Uuid::new_v4().simple().to_string())
+    }
+    /// Placeholder for reminders saved before they had identifiers, replaced on load.
+    fn unassigned() -> Self {
+        ReminderId(String::new())
+    }
+    fn is_unassigned(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Default for ReminderId {
+    fn default() -> Self {
+        ReminderId::new()
+    }
+}
+
+impl fmt::Display for ReminderId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ReminderId {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ReminderId(s.into()))
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Reminder {
+    #[serde(default = "ReminderId::unassigned")]
+    id: ReminderId,
     pub title: String,
     pub content: String,
     pub weekdays: Option<Vec<Weekday>>,
+    /// Repeats the reminder at growing intervals, moving up the ladder as it is acknowledged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spaced: Option<SpacedRepetition>,
+    /// Notifies the reminder again until it is acknowledged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation: Option<Escalation>,
+    /// Nags until the reminder is acknowledged often enough in the week or month.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<Quota>,
+    /// Only notify on weekdays other than holidays.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub business_days: bool,
+    /// Dates the reminder does not notify on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub except: Vec<NaiveDate>,
+    /// Changes to single occurrences of the reminder.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<OccurrenceOverride>,
+    /// First date the reminder notifies on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<NaiveDate>,
+    /// Last date the reminder notifies on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveDate>,
+    /// Repeats the reminder on a pattern other than every day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    /// Repeats the reminder on the dates of an iCalendar recurrence rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<RRule>,
+    /// Repeats the reminder within the day, from the time it is scheduled at.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<Repeat>,
     /// Application to open when reminder triggers.
     pub open: Option<ReminderOpen>,
+    /// Overrides the catch-up policy of the notifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up: Option<CatchUp>,
+    /// Time zone the reminder is scheduled in, such as `Europe/Berlin`, regardless of the
+    /// time zone of the notifier and system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Tz>,
+    /// How the reminder is notified when the clocks change for daylight saving time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dst: Option<DstPolicy>,
+}
+
+impl Reminder {
+    pub fn new(title: impl Into<String>, content: impl Into<String>) -> Self {
+        Reminder {
+            id: ReminderId::new(),
+            title: title.into(),
+            content: content.into(),
+            weekdays: None,
+            spaced: None,
+            escalation: None,
+            quota: None,
+            business_days: false,
+            except: Vec::new(),
+            overrides: Vec::new(),
+            from: None,
+            until: None,
+            recurrence: None,
+            rrule: None,
+            repeat: None,
+            open: None,
+            catch_up: None,
+            time_zone: None,
+            dst: None,
+        }
+    }
+    pub fn id(&self) -> &ReminderId {
+        &self.id
+    }
+    /// Actions to run when the reminder is notified.
+    pub fn actions(&self) -> Vec<ReminderAction> {
+        match self.open {
+            Some(ReminderOpen::Single(ref open)) => vec![ReminderAction::Open(open.clone())],
+            Some(ReminderOpen::Multiple(ref open)) => open.iter().cloned().map(ReminderAction::Open).collect(),
+            None => Vec::new(),
+        }
+    }
+    /// Times of day the reminder notifies at, when scheduled at `time`.
+    pub fn times(&self, time: NaiveTime) -> Vec<NaiveTime> {
+        match self.repeat {
+            Some(ref repeat) => repeat.times(time).collect(),
+            None => vec![time],
+        }
+    }
+    /// Override of the occurrence scheduled at the given date and time.
+    pub fn override_for(&self, scheduled: NaiveDateTime) -> Option<&OccurrenceOverride> {
+        self.overrides.iter().find(|occurrence| occurrence.scheduled == scheduled)
+    }
+    /// Whether the reminder no longer notifies on the given date or after.
+    pub fn expired_on(&self, date: NaiveDate) -> bool {
+        self.until.is_some_and(|until| date > until)
+    }
+    /// Whether the reminder is scheduled to notify on the given date.
+    pub fn occurs_on(&self, date: NaiveDate, holidays: &HolidayCalendar) -> bool {
+        let on_weekday = if let Some(ref weekdays) = self.weekdays {
+            weekdays.contains(&date.weekday())
+        } else {
+            true
+        };
+        on_weekday
+            && (!self.business_days || holidays.is_business_day(date))
+            && !self.except.contains(&date)
+            && self.from.is_none_or(|from| date >= from)
+            && self.until.is_none_or(|until| date <= until)
+            && self.recurrence.as_ref().is_none_or(|recurrence| recurrence.occurs_on(date, holidays))
+            && self.rrule.as_ref().is_none_or(|rrule| rrule.occurs_on(date))
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{duration, template, ActionError, ActionExecutor, OccurrenceKey, Reminder, ReminderAction};
+
+/// Reminder scheduled at a concrete date and time.
+#[derive(Clone, Copy)]
+pub struct Occurrence<'a> {
+    pub reminder: &'a Reminder,
+    /// Local date and time in the time zone of the reminder.
+    pub scheduled: NaiveDateTime,
+    /// Instant the occurrence is due.
+    pub at: DateTime<Utc>,
+    /// Local date and time of the deadline the occurrence alerts ahead of.
+    pub deadline: Option<NaiveDateTime>,
+    /// Acknowledgements still needed to meet the quota of the reminder.
+    pub remaining: Option<u32>,
+}
+
+/// Changes to a single occurrence of a reminder, leaving the others as they are.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OccurrenceOverride {
+    /// Date and time the occurrence is scheduled at, without the override.
+    pub scheduled: NaiveDateTime,
+    /// Time of day to notify at instead, on the same date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<NaiveTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl<'a> Occurrence<'a> {
+    /// Title of the occurrence, as overridden for it.
+    pub fn title(&self) -> &'a str {
+        self.reminder.override_for(self.scheduled)
+            .and_then(|occurrence| occurrence.title.as_deref())
+            .unwrap_or(&self.reminder.title)
+    }
+    /// Content of the occurrence, as overridden for it.
+    pub fn content(&self) -> &'a str {
+        self.reminder.override_for(self.scheduled)
+            .and_then(|occurrence| occurrence.content.as_deref())
+            .unwrap_or(&self.reminder.content)
+    }
+    /// How long after the occurrence its deadline is.
+    pub fn due_in(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| deadline - self.scheduled)
+    }
+    /// Context of the occurrence to notify along with it, such as `due in 1 day`.
+    pub fn context(&self) -> Option<String> {
+        self.variables().into_iter()
+            .find(|(name, _)| *name == "due")
+            .map(|(_, due)| format!("due {due}"))
+    }
+    /// Values of the placeholders in the title and content of the occurrence:
+    /// - `{due}`: when the deadline is, such as `in 1 day` or `now`.
+    /// - `{deadline}`: date and time of the deadline.
+    /// - `{remaining}`: acknowledgements still needed to meet the quota.
+    pub fn variables(&self) -> Vec<(&'static str, String)> {
+        let mut variables = Vec::new();
+        if let (Some(deadline), Some(due_in)) = (self.deadline, self.due_in()) {
+            let due = if due_in > Duration::zero() {
+                format!("in {}", duration::describe(due_in))
+            } else if due_in < Duration::zero() {
+                format!("{} ago", duration::describe(due_in))
+            } else {
+                "now".into()
+            };
+            variables.push(("due", due));
+            variables.push(("deadline", deadline.format("%Y-%m-%d %H:%M").to_string()));
+        }
+        if let Some(remaining) = self.remaining {
+            variables.push(("remaining", remaining.to_string()));
+        }
+        variables
+    }
+    pub fn key(&self) -> OccurrenceKey {
+        OccurrenceKey {
+            reminder: self.reminder.id().clone(),
+            scheduled: self.scheduled,
+        }
+    }
+}
+
+/// Occurrence to notify, along with the actions to run for it.
+#[derive(Clone)]
+pub struct FiredReminder<'a> {
+    pub reminder: &'a Reminder,
+    pub scheduled: NaiveDateTime,
+    pub at: DateTime<Utc>,
+    pub deadline: Option<NaiveDateTime>,
+    pub remaining: Option<u32>,
+    /// How many times the occurrence was notified, including this time.
+    pub attempt: u32,
+    /// Title, with its placeholders filled in.
+    pub title: String,
+    /// Content, with its placeholders filled in.
+    pub content: String,
+    pub actions: Vec<ReminderAction>,
+}
+
+impl<'a> FiredReminder<'a> {
+    pub(crate) fn new(occurrence: Occurrence<'a>) -> Self {
+        let variables = occurrence.variables();
+        FiredReminder {
+            reminder: occurrence.reminder,
+            scheduled: occurrence.scheduled,
+            at: occurrence.at,
+            deadline: occurrence.deadline,
+            remaining: occurrence.remaining,
+            attempt: 1,
+            title: template::render(occurrence.title(), &variables),
+            content: template::render(occurrence.content(), &variables),
+            actions: occurrence.reminder.actions(),
+        }
+    }
+    pub fn occurrence(&self) -> Occurrence<'a> {
+        Occurrence {
+            reminder: self.reminder,
+            scheduled: self.scheduled,
+            at: self.at,
+            deadline: self.deadline,
+            remaining: self.remaining,
+        }
+    }
+    /// Whether the notification is escalated, because the occurrence was not acknowledged after
+    /// as many attempts as its [`Escalation`](crate::Escalation) allows.
+    pub fn escalated(&self) -> bool {
+        self.reminder.escalation.as_ref().is_some_and(|escalation| escalation.escalates(self.attempt))
+    }
+    /// Context to notify along with the reminder, such as `due in 1 day`.
+    pub fn context(&self) -> Option<String> {
+        self.occurrence().context()
+    }
+    pub fn key(&self) -> OccurrenceKey {
+        self.occurrence().key()
+    }
+    /// Runs every action with the executor, returns the ones that failed.
+    pub fn run_actions(&self, executor: &mut impl ActionExecutor) -> Vec<ActionError> {
+        self.actions.iter()
+            .filter_map(|action| {
+                executor.execute(self, action)
+                    .err()
+                    .map(|source| ActionError {
+                        action: action.clone(),
+                        source,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Result of checking for reminders, iterates over the reminders to notify.
+#[derive(Default)]
+pub struct ReminderCheck<'a> {
+    pub(crate) fired: Vec<FiredReminder<'a>>,
+    pub(crate) missed: Vec<Occurrence<'a>>,
+}
+
+impl<'a> ReminderCheck<'a> {
+    /// Occurrences to notify.
+    pub fn fired(&self) -> &[FiredReminder<'a>] {
+        &self.fired
+    }
+    /// Occurrences that were due while not checking, and were dropped by their [`CatchUp`](crate::CatchUp) policy.
+    pub fn missed(&self) -> &[Occurrence<'a>] {
+        &self.missed
+    }
+}
+
+impl<'a> IntoIterator for ReminderCheck<'a> {
+    type Item = FiredReminder<'a>;
+    type IntoIter = std::vec::IntoIter<FiredReminder<'a>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.fired.into_iter()
+    }
+}
@@ -0,0 +1,66 @@
+use std::num::NonZeroU32;
+
+use chrono::{Datelike, Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Habit to do a number of times per week or month, on any day, which nags until it is done
+/// that often.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Quota {
+    pub count: NonZeroU32,
+    #[serde(default)]
+    pub per: QuotaPeriod,
+}
+
+/// Period a quota is counted over.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaPeriod {
+    /// Weeks starting on Monday.
+    #[default]
+    Week,
+    Month,
+}
+
+/// How often a quota was met in a period.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QuotaProgress {
+    /// First date of the period.
+    pub period: NaiveDate,
+    pub done: u32,
+}
+
+impl QuotaPeriod {
+    /// First date of the period the date is in.
+    pub fn start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            QuotaPeriod::Week => date - Days::new(date.weekday().num_days_from_monday().into()),
+            QuotaPeriod::Month => date.with_day(1).unwrap(),
+        }
+    }
+}
+
+impl Quota {
+    /// How many more times the habit is to be done in the period of the date.
+    pub fn remaining(&self, date: NaiveDate, progress: Option<QuotaProgress>) -> u32 {
+        let done = progress
+            .filter(|progress| progress.period == self.per.start(date))
+            .map_or(0, |progress| progress.done);
+        self.count.get().saturating_sub(done)
+    }
+    /// Progress after doing the habit on the date, which does not count once a later period
+    /// started.
+    pub(crate) fn count(&self, progress: Option<QuotaProgress>, date: NaiveDate) -> QuotaProgress {
+        let period = self.per.start(date);
+        if let Some(progress) = progress.filter(|progress| progress.period > period) {
+            return progress;
+        }
+        let done = progress
+            .filter(|progress| progress.period == period)
+            .map_or(0, |progress| progress.done);
+        QuotaProgress {
+            period,
+            done: done + 1,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::num::{NonZeroI8, NonZeroU32};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::HolidayCalendar;
+
+/// Which dates a reminder repeats on.
+///
+/// Intervals are counted from an anchor date, which defaults to 1970-01-01. Dates before the
+/// anchor never match.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(tag = "frequency", rename_all = "snake_case", try_from = "UncheckedRecurrence")]
+pub enum Recurrence {
+    /// Every `interval` days.
+    Daily {
+        #[serde(default = "one")]
+        interval: NonZeroU32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        anchor: Option<NaiveDate>,
+    },
+    /// Every `interval` weeks, on the given weekdays, or the weekday of the anchor when empty.
+    /// Weeks start on Monday.
+    Weekly {
+        #[serde(default = "one")]
+        interval: NonZeroU32,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        on: Vec<Weekday>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        anchor: Option<NaiveDate>,
+    },
+    /// On a day of every month, counted from the end of the month when negative, so `-1` is the
+    /// last day. Months without that day are skipped.
+    MonthlyByDay {
+        day: NonZeroI8,
+    },
+    /// On the `nth` weekday of every month, counted from the end of the month when negative, so
+    /// `-1` is the last one. Months without that weekday are skipped.
+    MonthlyByWeekday {
+        nth: NonZeroI8,
+        weekday: Weekday,
+    },
+    /// On the `nth` business day of every month, counted from the end of the month when
+    /// negative, so `1` is the first one and `-1` the last one. Business days are weekdays other
+    /// than holidays.
+    MonthlyByBusinessDay {
+        nth: NonZeroI8,
+    },
+    /// On a day of every year, February 29th only on leap years.
+    Yearly {
+        month: u32,
+        day: u32,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RecurrenceError {
+    #[error("invalid day of month `{0}`, expected 1 to 31 or -31 to -1")]
+    DayOfMonth(i8),
+    #[error("invalid nth weekday `{0}`, expected 1 to 5 or -5 to -1")]
+    NthWeekday(i8),
+    #[error("invalid nth business day `{0}`, expected 1 to 23 or -23 to -1")]
+    NthBusinessDay(i8),
+    #[error("invalid yearly date, month `{month}` day `{day}`")]
+    Date {
+        month: u32,
+        day: u32,
+    },
+}
+
+/// [`Recurrence`] as written, before its values are checked to be in range.
+#[derive(Deserialize)]
+#[serde(tag = "frequency", rename_all = "snake_case")]
+enum UncheckedRecurrence {
+    Daily {
+        #[serde(default = "one")]
+        interval: NonZeroU32,
+        #[serde(default)]
+        anchor: Option<NaiveDate>,
+    },
+    Weekly {
+        #[serde(default = "one")]
+        interval: NonZeroU32,
+        #[serde(default)]
+        on: Vec<Weekday>,
+        #[serde(default)]
+        anchor: Option<NaiveDate>,
+    },
+    MonthlyByDay {
+        day: NonZeroI8,
+    },
+    MonthlyByWeekday {
+        nth: NonZeroI8,
+        weekday: Weekday,
+    },
+    MonthlyByBusinessDay {
+        nth: NonZeroI8,
+    },
+    Yearly {
+        month: u32,
+        day: u32,
+    },
+}
+
+impl TryFrom<UncheckedRecurrence> for Recurrence {
+    type Error = RecurrenceError;
+    fn try_from(recurrence: UncheckedRecurrence) -> Result<Self, Self::Error> {
+        Ok(match recurrence {
+            UncheckedRecurrence::Daily { interval, anchor } => Recurrence::Daily { interval, anchor },
+            UncheckedRecurrence::Weekly { interval, on, anchor } => Recurrence::Weekly { interval, on, anchor },
+            UncheckedRecurrence::MonthlyByDay { day } => {
+                if !(-31..=31).contains(&day.get()) {
+                    return Err(RecurrenceError::DayOfMonth(day.get()));
+                }
+                Recurrence::MonthlyByDay { day }
+            },
+            UncheckedRecurrence::MonthlyByWeekday { nth, weekday } => {
+                if !(-5..=5).contains(&nth.get()) {
+                    return Err(RecurrenceError::NthWeekday(nth.get()));
+                }
+                Recurrence::MonthlyByWeekday { nth, weekday }
+            },
+            UncheckedRecurrence::MonthlyByBusinessDay { nth } => {
+                if !(-23..=23).contains(&nth.get()) {
+                    return Err(RecurrenceError::NthBusinessDay(nth.get()));
+                }
+                Recurrence::MonthlyByBusinessDay { nth }
+            },
+            UncheckedRecurrence::Yearly { month, day } => {
+                // Checked against a leap year, so February 29th is allowed.
+                if NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+                    return Err(RecurrenceError::Date { month, day });
+                }
+                Recurrence::Yearly { month, day }
+            },
+        })
+    }
+}
+
+fn one() -> NonZeroU32 {
+    NonZeroU32::MIN
+}
+
+const EPOCH: NaiveDate = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+
+impl Recurrence {
+    pub fn occurs_on(&self, date: NaiveDate, holidays: &HolidayCalendar) -> bool {
+        match self {
+            Recurrence::Daily { interval, anchor } => {
+                let days = (date - anchor.unwrap_or(EPOCH)).num_days();
+                days >= 0 && days % i64::from(interval.get()) == 0
+            },
+            Recurrence::Weekly { interval, on, anchor } => {
+                let anchor = anchor.unwrap_or(EPOCH);
+                let on_weekday = if on.is_empty() {
+                    date.weekday() == anchor.weekday()
+                } else {
+                    on.contains(&date.weekday())
+                };
+                let weeks = (week_start(date) - week_start(anchor)).num_weeks();
+                on_weekday && date >= anchor && weeks % i64::from(interval.get()) == 0
+            },
+            Recurrence::MonthlyByDay { day } => {
+                let day = day.get();
+                if day > 0 {
+                    date.day() == day as u32
+                } else {
+                    days_in_month(date) - date.day() == day.unsigned_abs() as u32 - 1
+                }
+            },
+            Recurrence::MonthlyByWeekday { nth, weekday } => {
+                let nth = nth.get();
+                date.weekday() == *weekday && if nth > 0 {
+                    (date.day() - 1) / 7 + 1 == nth as u32
+                } else {
+                    (days_in_month(date) - date.day()) / 7 + 1 == nth.unsigned_abs() as u32
+                }
+            },
+            Recurrence::MonthlyByBusinessDay { nth } => {
+                let nth = nth.get();
+                let month_start = date.with_day(1).unwrap();
+                let month_end = date.with_day(days_in_month(date)).unwrap();
+                let business_days = |from: NaiveDate, to: NaiveDate| from.iter_days()
+                    .take_while(|day| *day <= to)
+                    .filter(|day| holidays.is_business_day(*day))
+                    .count();
+                holidays.is_business_day(date) && if nth > 0 {
+                    business_days(month_start, date) == nth as usize
+                } else {
+                    business_days(date, month_end) == nth.unsigned_abs() as usize
+                }
+            },
+            Recurrence::Yearly { month, day } => date.month() == *month && date.day() == *day,
+        }
+    }
+}
+
+/// Monday of the week of the date.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(date.weekday().num_days_from_monday().into())
+}
+
+pub(crate) fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap().pred_opt().unwrap().day()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn business_days_are_counted_around_holidays() {
+        let holidays: HolidayCalendar = [date(2026, 5, 1), date(2026, 12, 31)].into_iter().collect();
+        let first = Recurrence::MonthlyByBusinessDay { nth: NonZeroI8::new(1).unwrap() };
+        assert!(!first.occurs_on(date(2026, 5, 1), &holidays));
+        assert!(first.occurs_on(date(2026, 5, 4), &holidays));
+        assert!(first.occurs_on(date(2026, 6, 1), &holidays));
+        let last = Recurrence::MonthlyByBusinessDay { nth: NonZeroI8::new(-1).unwrap() };
+        assert!(!last.occurs_on(date(2026, 12, 31), &holidays));
+        assert!(last.occurs_on(date(2026, 12, 30), &holidays));
+        assert!(last.occurs_on(date(2026, 4, 30), &holidays));
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{Duration, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Repeats a reminder every so often within a day, from the time it is scheduled at.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Repeat {
+    #[serde(with = "crate::duration::positive")]
+    pub every: Duration,
+    /// Last time of day to repeat at, the end of the day by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveTime>,
+}
+
+impl Repeat {
+    /// Times of day to notify at, starting at `start`.
+    pub fn times(&self, start: NaiveTime) -> impl Iterator<Item = NaiveTime> + '_ {
+        std::iter::successors(Some(start), move |time| {
+            let (next, wrapped) = time.overflowing_add_signed(self.every);
+            (wrapped == 0).then_some(next)
+        })
+        .take_while(move |time| self.until.is_none_or(|until| *time <= until))
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::Reminder;
+
+/// Sequence of reminders at offsets from one anchor time, such as a bedtime wind-down, moved and
+/// enabled as a unit.
+#[derive(Serialize, Deserialize)]
+pub struct Routine {
+    pub title: String,
+    /// Time of day the offsets of the steps are counted from.
+    pub anchor: NaiveTime,
+    /// Days the routine is anchored on, every day when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weekdays: Option<Vec<Weekday>>,
+    #[serde(default = "enabled", skip_serializing_if = "is_enabled")]
+    pub enabled: bool,
+    /// Steps in the order they are notified.
+    pub steps: Vec<RoutineStep>,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+fn is_enabled(enabled: &bool) -> bool {
+    *enabled
+}
+
+/// Reminder of a routine, notified at an offset from its anchor, before it when negative.
+#[derive(Serialize, Deserialize)]
+pub struct RoutineStep {
+    #[serde(with = "crate::duration")]
+    pub offset: Duration,
+    #[serde(flatten)]
+    pub reminder: Reminder,
+}
+
+impl Routine {
+    pub fn new(title: impl Into<String>, anchor: NaiveTime) -> Self {
+        Routine {
+            title: title.into(),
+            anchor,
+            weekdays: None,
+            enabled: true,
+            steps: Vec::new(),
+        }
+    }
+    /// Adds a step notified at `offset` from the anchor.
+    pub fn step(mut self, offset: Duration, reminder: Reminder) -> Self {
+        self.steps.push(RoutineStep {
+            offset,
+            reminder,
+        });
+        self
+    }
+    /// Whether the routine is anchored on the given date.
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        self.enabled && self.weekdays.as_ref().is_none_or(|weekdays| weekdays.contains(&date.weekday()))
+    }
+    /// Days the steps are at most away from the date they are anchored on.
+    pub(crate) fn spread(&self) -> i64 {
+        self.steps.iter()
+            .map(|step| step.offset.num_days().abs() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
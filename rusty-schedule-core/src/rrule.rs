@@ -0,0 +1,459 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::recurrence::days_in_month;
+
+/// Recurrence rule from RFC 5545, such as `FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20270101T000000Z`.
+///
+/// The rule may be preceded by a `DTSTART` line, anchoring `INTERVAL` and `COUNT`, and followed
+/// by `EXDATE` lines of excluded dates, each line separated by a newline. Only dates are taken
+/// from the rule, the time of day is the one the reminder is scheduled at. `UNTIL` is compared
+/// with local time, or with UTC when it ends in `Z`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(try_from = "String", into = "String")]
+pub struct RRule {
+    text: String,
+    frequency: Frequency,
+    interval: u32,
+    start: Option<NaiveDate>,
+    until: Option<Until>,
+    /// Date of the last occurrence allowed by `COUNT`.
+    last: Option<NaiveDate>,
+    by_month: Vec<u32>,
+    by_month_day: Vec<i32>,
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_set_position: Vec<i32>,
+    week_start: Weekday,
+    exceptions: Vec<NaiveDate>,
+}
+
+/// End of the rule, inclusive.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Until {
+    Local(NaiveDateTime),
+    Utc(DateTime<Utc>),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RRuleError {
+    #[error("missing FREQ")]
+    MissingFrequency,
+    #[error("unsupported {0} `{1}`")]
+    Unsupported(&'static str, String),
+    #[error("invalid {0} `{1}`")]
+    Invalid(&'static str, String),
+    #[error("COUNT and UNTIL can't both be set")]
+    CountAndUntil,
+    #[error("COUNT requires a DTSTART")]
+    CountWithoutStart,
+}
+
+/// Furthest `COUNT` looks for occurrences, in periods.
+const COUNT_HORIZON: u32 = 100_000;
+
+impl RRule {
+    /// Whether the rule occurs on the date. An `UNTIL` in UTC also allows the date after its own,
+    /// which local dates may be on, leaving the exact end to [`RRule::allows`].
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        self.start.is_none_or(|start| date >= start)
+            && self.until.is_none_or(|until| match until {
+                Until::Local(until) => date <= until.date(),
+                Until::Utc(until) => date <= until.date_naive() + Days::new(1),
+            })
+            && self.last.is_none_or(|last| date <= last)
+            && !self.exceptions.contains(&date)
+            && self.period_dates(self.period_of(date)).contains(&date)
+    }
+    /// Whether the rule, on its own, allows an occurrence at the given local date and time, which
+    /// is the instant `at`. `UNTIL` may end the rule partway through a day.
+    pub(crate) fn allows(&self, scheduled: NaiveDateTime, at: DateTime<Utc>) -> bool {
+        self.until.is_none_or(|until| match until {
+            Until::Local(until) => scheduled <= until,
+            Until::Utc(until) => at <= until,
+        })
+    }
+    fn anchor(&self) -> NaiveDate {
+        self.start.unwrap_or(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+    }
+    /// First date of the period containing the date, whether or not the period is skipped by `INTERVAL`.
+    fn period_of(&self, date: NaiveDate) -> NaiveDate {
+        match self.frequency {
+            Frequency::Daily => date,
+            Frequency::Weekly => {
+                let offset = (7 + date.weekday().num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+                date - Days::new(offset.into())
+            },
+            Frequency::Monthly => date.with_day(1).unwrap(),
+            Frequency::Yearly => date.with_ordinal(1).unwrap(),
+        }
+    }
+    /// Dates of the period starting at `period`, empty when the period is skipped by `INTERVAL`.
+    fn period_dates(&self, period: NaiveDate) -> Vec<NaiveDate> {
+        let anchor = self.period_of(self.anchor());
+        let elapsed = match self.frequency {
+            Frequency::Daily => (period - anchor).num_days(),
+            Frequency::Weekly => (period - anchor).num_weeks(),
+            Frequency::Monthly => months_between(anchor, period),
+            Frequency::Yearly => i64::from(period.year() - anchor.year()),
+        };
+        if elapsed < 0 || elapsed % i64::from(self.interval) != 0 {
+            return Vec::new();
+        }
+        let mut dates: Vec<_> = match self.frequency {
+            Frequency::Daily => vec![period],
+            Frequency::Weekly => period.iter_days().take(7)
+                .filter(|date| if self.by_day.is_empty() {
+                    date.weekday() == self.anchor().weekday()
+                } else {
+                    self.by_day.iter().any(|(_, weekday)| *weekday == date.weekday())
+                })
+                .collect(),
+            Frequency::Monthly => self.month_dates(period),
+            Frequency::Yearly => if self.by_month.is_empty() && self.by_month_day.is_empty() && !self.by_day.is_empty() {
+                let year_end = period.with_month(12).unwrap().with_day(31).unwrap();
+                period.iter_days()
+                    .take_while(|date| *date <= year_end)
+                    .filter(|date| matches_by_day(&self.by_day, *date, period, year_end))
+                    .collect()
+            } else {
+                let months = if self.by_month.is_empty() {
+                    vec![self.anchor().month()]
+                } else {
+                    self.by_month.clone()
+                };
+                let mut dates: Vec<_> = months.into_iter()
+                    .filter_map(|month| period.with_month(month))
+                    .flat_map(|month| self.month_dates(month))
+                    .collect();
+                dates.sort();
+                dates
+            },
+        };
+        dates.retain(|date| {
+            (self.by_month.is_empty() || self.by_month.contains(&date.month()))
+                && (self.frequency != Frequency::Daily || self.by_month_day.is_empty() || matches_month_day(&self.by_month_day, *date))
+                && (self.frequency != Frequency::Daily || self.by_day.is_empty() || self.by_day.iter().any(|(_, weekday)| *weekday == date.weekday()))
+        });
+        if !self.by_set_position.is_empty() {
+            let count = dates.len() as i32;
+            dates = self.by_set_position.iter()
+                .filter_map(|position| {
+                    let index = if *position > 0 { position - 1 } else { count + position };
+                    dates.get(usize::try_from(index).ok()?).copied()
+                })
+                .collect();
+            dates.sort();
+            dates.dedup();
+        }
+        dates
+    }
+    /// Dates of the month starting at `month` matching `BYMONTHDAY` and `BYDAY`, or the day of the anchor.
+    fn month_dates(&self, month: NaiveDate) -> Vec<NaiveDate> {
+        let month_end = month.with_day(days_in_month(month)).unwrap();
+        month.iter_days()
+            .take_while(|date| *date <= month_end)
+            .filter(|date| if self.by_month_day.is_empty() && self.by_day.is_empty() {
+                date.day() == self.anchor().day()
+            } else {
+                (self.by_month_day.is_empty() || matches_month_day(&self.by_month_day, *date))
+                    && (self.by_day.is_empty() || matches_by_day(&self.by_day, *date, month, month_end))
+            })
+            .collect()
+    }
+    /// Date of the last occurrence of the rule when it only occurs `count` times.
+    fn last_of(&self, count: u32) -> Option<NaiveDate> {
+        let mut period = self.period_of(self.anchor());
+        let mut remaining = count;
+        for _ in 0..COUNT_HORIZON {
+            for date in self.period_dates(period) {
+                if date >= self.anchor() {
+                    remaining -= 1;
+                    if remaining == 0 {
+                        return Some(date);
+                    }
+                }
+            }
+            period = match self.frequency {
+                Frequency::Daily => period + Days::new(1),
+                Frequency::Weekly => period + Days::new(7),
+                Frequency::Monthly => period + Months::new(1),
+                Frequency::Yearly => period + Months::new(12),
+            };
+        }
+        None
+    }
+}
+
+fn months_between(from: NaiveDate, to: NaiveDate) -> i64 {
+    i64::from(to.year() - from.year()) * 12 + i64::from(to.month()) - i64::from(from.month())
+}
+
+fn matches_month_day(by_month_day: &[i32], date: NaiveDate) -> bool {
+    let day = date.day() as i32;
+    let from_end = day - days_in_month(date) as i32 - 1;
+    by_month_day.iter().any(|month_day| *month_day == day || *month_day == from_end)
+}
+
+/// Whether the date matches `BYDAY`, counting ordinals within `first` and `last`.
+fn matches_by_day(by_day: &[(Option<i32>, Weekday)], date: NaiveDate, first: NaiveDate, last: NaiveDate) -> bool {
+    by_day.iter().any(|(nth, weekday)| {
+        *weekday == date.weekday() && match nth {
+            Some(nth) if *nth > 0 => (date - first).num_days() / 7 + 1 == i64::from(*nth),
+            Some(nth) => (last - date).num_days() / 7 + 1 == i64::from(-nth),
+            None => true,
+        }
+    })
+}
+
+fn parse_weekday(text: &str) -> Option<Weekday> {
+    Some(match text {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Parses a `DATE` or `DATE-TIME` value, a date alone being the start of the day.
+pub(crate) fn parse_date_time(text: &str) -> Option<NaiveDateTime> {
+    let text = text.trim_end_matches('Z');
+    NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S").ok()
+        .or_else(|| NaiveDate::parse_from_str(text, "%Y%m%d").ok().map(|date| date.and_time(Default::default())))
+}
+
+fn parse_list<T>(name: &'static str, value: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, RRuleError> {
+    value.split(',')
+        .map(|item| parse(item).ok_or_else(|| RRuleError::Invalid(name, item.into())))
+        .collect()
+}
+
+impl FromStr for RRule {
+    type Err = RRuleError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut rule = None;
+        let mut start = None;
+        let mut exceptions = Vec::new();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            // Parameters, such as `;TZID=...`, come before the value.
+            let (name, value) = match line.split_once(':') {
+                Some((name, value)) => (name.split(';').next().unwrap(), line.rsplit(':').next().unwrap_or(value)),
+                None => ("RRULE", line),
+            };
+            match name {
+                "RRULE" => rule = Some(value),
+                "DTSTART" => start = Some(parse_date_time(value).ok_or_else(|| RRuleError::Invalid("DTSTART", value.into()))?.date()),
+                "EXDATE" => exceptions.extend(parse_list("EXDATE", value, |date| parse_date_time(date).map(|date| date.date()))?),
+                _ => return Err(RRuleError::Unsupported("property", name.into())),
+            }
+        }
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_month = Vec::new();
+        let mut by_month_day = Vec::new();
+        let mut by_day = Vec::new();
+        let mut by_set_position = Vec::new();
+        let mut week_start = Weekday::Mon;
+        for part in rule.ok_or(RRuleError::MissingFrequency)?.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part.split_once('=').ok_or_else(|| RRuleError::Invalid("rule part", part.into()))?;
+            match name {
+                "FREQ" => frequency = Some(match value {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    _ => return Err(RRuleError::Unsupported("FREQ", value.into())),
+                }),
+                "INTERVAL" => interval = value.parse().ok().filter(|interval| *interval > 0)
+                    .ok_or_else(|| RRuleError::Invalid("INTERVAL", value.into()))?,
+                "COUNT" => count = Some(value.parse().ok().filter(|count| *count > 0)
+                    .ok_or_else(|| RRuleError::Invalid("COUNT", value.into()))?),
+                "UNTIL" => until = Some({
+                    let until = parse_date_time(value).ok_or_else(|| RRuleError::Invalid("UNTIL", value.into()))?;
+                    if value.ends_with('Z') {
+                        Until::Utc(until.and_utc())
+                    } else {
+                        Until::Local(until)
+                    }
+                }),
+                "BYMONTH" => by_month = parse_list("BYMONTH", value, |month| month.parse().ok().filter(|month| (1..=12).contains(month)))?,
+                "BYMONTHDAY" => by_month_day = parse_list("BYMONTHDAY", value, |day| {
+                    day.parse().ok().filter(|day: &i32| *day != 0 && day.abs() <= 31)
+                })?,
+                "BYDAY" => by_day = parse_list("BYDAY", value, |day| {
+                    let (nth, weekday) = day.split_at(day.len().checked_sub(2)?);
+                    let nth = if nth.is_empty() {
+                        None
+                    } else {
+                        Some(nth.parse().ok().filter(|nth: &i32| *nth != 0 && nth.abs() <= 53)?)
+                    };
+                    Some((nth, parse_weekday(weekday)?))
+                })?,
+                "BYSETPOS" => by_set_position = parse_list("BYSETPOS", value, |position| {
+                    position.parse().ok().filter(|position: &i32| *position != 0 && position.abs() <= 366)
+                })?,
+                "WKST" => week_start = parse_weekday(value).ok_or_else(|| RRuleError::Invalid("WKST", value.into()))?,
+                _ => return Err(RRuleError::Unsupported("rule part", name.into())),
+            }
+        }
+        if count.is_some() && until.is_some() {
+            return Err(RRuleError::CountAndUntil);
+        }
+        if count.is_some() && start.is_none() {
+            return Err(RRuleError::CountWithoutStart);
+        }
+        let mut rrule = RRule {
+            text: text.into(),
+            frequency: frequency.ok_or(RRuleError::MissingFrequency)?,
+            interval,
+            start,
+            until,
+            last: None,
+            by_month,
+            by_month_day,
+            by_day,
+            by_set_position,
+            week_start,
+            exceptions,
+        };
+        if let Some(count) = count {
+            rrule.last = Some(rrule.last_of(count).unwrap_or(NaiveDate::MIN));
+        }
+        Ok(rrule)
+    }
+}
+
+impl TryFrom<String> for RRule {
+    type Error = RRuleError;
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<RRule> for String {
+    fn from(rrule: RRule) -> Self {
+        rrule.text
+    }
+}
+
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone};
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn rule(text: &str) -> RRule {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn count_ends_after_occurrences_from_start() {
+        let rrule = rule("DTSTART:20260105\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3");
+        assert!(!rrule.occurs_on(date(2026, 1, 1)));
+        assert!(rrule.occurs_on(date(2026, 1, 5)));
+        assert!(rrule.occurs_on(date(2026, 1, 7)));
+        assert!(rrule.occurs_on(date(2026, 1, 12)));
+        assert!(!rrule.occurs_on(date(2026, 1, 14)));
+    }
+
+    #[test]
+    fn local_until_ends_partway_through_the_day() {
+        let rrule = rule("FREQ=DAILY;UNTIL=20260110T090000");
+        assert!(rrule.occurs_on(date(2026, 1, 10)));
+        assert!(!rrule.occurs_on(date(2026, 1, 11)));
+        let at = |time| date(2026, 1, 10).and_time(time);
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let half_past = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        // Local `UNTIL` ignores the instant.
+        assert!(rrule.allows(at(nine), at(half_past).and_utc()));
+        assert!(!rrule.allows(at(half_past), at(nine).and_utc()));
+    }
+
+    #[test]
+    fn utc_until_is_compared_with_the_instant() {
+        let rrule = rule("FREQ=DAILY;UNTIL=20260110T230000Z");
+        let tokyo = chrono_tz::Asia::Tokyo;
+        // 08:00 in Tokyo on the 11th is 23:00 UTC on the 10th.
+        let scheduled = date(2026, 1, 11).and_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap());
+        assert!(rrule.occurs_on(scheduled.date()));
+        assert!(rrule.allows(scheduled, tokyo.from_local_datetime(&scheduled).unwrap().to_utc()));
+        let later = scheduled + chrono::Duration::minutes(30);
+        assert!(!rrule.allows(later, tokyo.from_local_datetime(&later).unwrap().to_utc()));
+        assert!(!rrule.occurs_on(date(2026, 1, 12)));
+    }
+
+    #[test]
+    fn set_position_picks_from_the_period() {
+        let rrule = rule("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1");
+        assert!(rrule.occurs_on(date(2026, 1, 30)));
+        assert!(!rrule.occurs_on(date(2026, 1, 29)));
+        assert!(!rrule.occurs_on(date(2026, 1, 31)));
+        assert!(rrule.occurs_on(date(2026, 2, 27)));
+    }
+
+    #[test]
+    fn exdate_excludes_dates() {
+        let rrule = rule("RRULE:FREQ=DAILY\nEXDATE:20260102,20260104T090000");
+        assert!(rrule.occurs_on(date(2026, 1, 1)));
+        assert!(!rrule.occurs_on(date(2026, 1, 2)));
+        assert!(rrule.occurs_on(date(2026, 1, 3)));
+        assert!(!rrule.occurs_on(date(2026, 1, 4)));
+    }
+
+    #[test]
+    fn negative_by_day_counts_from_the_end() {
+        let rrule = rule("FREQ=MONTHLY;BYDAY=-1FR");
+        assert!(rrule.occurs_on(date(2026, 1, 30)));
+        assert!(!rrule.occurs_on(date(2026, 1, 23)));
+        assert!(rrule.occurs_on(date(2026, 2, 27)));
+    }
+
+    #[test]
+    fn negative_by_month_day_counts_from_the_end() {
+        let rrule = rule("FREQ=MONTHLY;BYMONTHDAY=-1");
+        assert!(rrule.occurs_on(date(2026, 1, 31)));
+        assert!(rrule.occurs_on(date(2026, 2, 28)));
+        assert!(!rrule.occurs_on(date(2026, 2, 27)));
+        assert!(rrule.occurs_on(date(2028, 2, 29)));
+        assert!(!rrule.occurs_on(date(2028, 2, 28)));
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        assert!(matches!("".parse::<RRule>(), Err(RRuleError::MissingFrequency)));
+        assert!(matches!("INTERVAL=2".parse::<RRule>(), Err(RRuleError::MissingFrequency)));
+        assert!(matches!("FREQ=HOURLY".parse::<RRule>(), Err(RRuleError::Unsupported("FREQ", _))));
+        assert!(matches!("FREQ=DAILY;BYHOUR=9".parse::<RRule>(), Err(RRuleError::Unsupported("rule part", _))));
+        assert!(matches!("FREQ=DAILY;INTERVAL=0".parse::<RRule>(), Err(RRuleError::Invalid("INTERVAL", _))));
+        assert!(matches!("FREQ=DAILY;BYMONTHDAY=32".parse::<RRule>(), Err(RRuleError::Invalid("BYMONTHDAY", _))));
+        assert!(matches!("FREQ=DAILY;BYDAY=XX".parse::<RRule>(), Err(RRuleError::Invalid("BYDAY", _))));
+        assert!(matches!("FREQ=DAILY;UNTIL=tomorrow".parse::<RRule>(), Err(RRuleError::Invalid("UNTIL", _))));
+        assert!(matches!("FREQ=DAILY;COUNT=2;UNTIL=20260101".parse::<RRule>(), Err(RRuleError::CountAndUntil)));
+        assert!(matches!("FREQ=DAILY;COUNT=2".parse::<RRule>(), Err(RRuleError::CountWithoutStart)));
+    }
+}
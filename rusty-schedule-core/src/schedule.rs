@@ -0,0 +1,197 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::{state::NotifierState, CatchUp, CronSchedule, Deadline, HolidayCalendar, Occurrence, Reminder, ReminderId, Routine, TravelMode, Zone};
+
+/// Reminders of a notifier, as saved to and loaded from disk.
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct Schedule {
+    pub(crate) reminders: HashMap<NaiveTime, Vec<Reminder>>,
+    /// Reminders pinned to a specific date and time, notified only once.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) once: HashMap<NaiveDateTime, Vec<Reminder>>,
+    /// Reminders due at a date and time, alerting ahead of it.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) deadlines: HashMap<NaiveDateTime, Vec<Deadline>>,
+    /// Sequences of reminders at offsets from an anchor time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) routines: Vec<Routine>,
+    /// Reminders scheduled by cron expressions.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) cron: HashMap<CronSchedule, Vec<Reminder>>,
+    /// Catch-up policy for reminders without their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) catch_up: Option<CatchUp>,
+    /// Home time zone of reminders without their own, the time zone of the system when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) time_zone: Option<Tz>,
+    /// Whether reminders without their own time zone follow the system when it leaves home.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) travel_mode: Option<TravelMode>,
+    /// File of the holidays, relative to the reminders.
+    #[serde(rename = "holidays", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) holidays_path: Option<PathBuf>,
+    #[serde(skip)]
+    pub(crate) holidays: HolidayCalendar,
+    /// Whether reminders whose `until` date has passed are removed when saving.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) prune_expired: bool,
+}
+
+impl Schedule {
+    pub(crate) fn reminders(&self) -> impl Iterator<Item = &Reminder> {
+        self.reminders.values()
+            .chain(self.once.values())
+            .chain(self.cron.values())
+            .flatten()
+            .chain(self.deadlines.values().flatten().map(|deadline| &deadline.reminder))
+            .chain(self.routines.iter().flat_map(|routine| &routine.steps).map(|step| &step.reminder))
+    }
+    pub(crate) fn get(&self, id: &ReminderId) -> Option<&Reminder> {
+        self.reminders().find(|reminder| reminder.id() == id)
+    }
+    pub(crate) fn reminders_mut(&mut self) -> impl Iterator<Item = &mut Reminder> {
+        self.reminders.values_mut()
+            .chain(self.once.values_mut())
+            .chain(self.cron.values_mut())
+            .flatten()
+            .chain(self.deadlines.values_mut().flatten().map(|deadline| &mut deadline.reminder))
+            .chain(self.routines.iter_mut().flat_map(|routine| &mut routine.steps).map(|step| &mut step.reminder))
+    }
+    pub(crate) fn remove(&mut self, id: &ReminderId) -> Option<Reminder> {
+        if let Some(deadlines) = self.deadlines.values_mut().find(|deadlines| deadlines.iter().any(|deadline| deadline.reminder.id() == id)) {
+            let index = deadlines.iter().position(|deadline| deadline.reminder.id() == id)?;
+            let deadline = deadlines.remove(index);
+            self.deadlines.retain(|_, deadlines| !deadlines.is_empty());
+            return Some(deadline.reminder);
+        }
+        for routine in &mut self.routines {
+            if let Some(index) = routine.steps.iter().position(|step| step.reminder.id() == id) {
+                return Some(routine.steps.remove(index).reminder);
+            }
+        }
+        let reminders = self.reminders.values_mut()
+            .chain(self.once.values_mut())
+            .chain(self.cron.values_mut())
+            .find(|reminders| reminders.iter().any(|reminder| reminder.id() == id))?;
+        let index = reminders.iter().position(|reminder| reminder.id() == id)?;
+        let reminder = reminders.remove(index);
+        self.reminders.retain(|_, reminders| !reminders.is_empty());
+        self.once.retain(|_, reminders| !reminders.is_empty());
+        self.cron.retain(|_, reminders| !reminders.is_empty());
+        Some(reminder)
+    }
+    /// Catch-up policy of the reminder, falling back to the one of the schedule.
+    pub(crate) fn catch_up(&self, reminder: &Reminder) -> CatchUp {
+        reminder.catch_up.or(self.catch_up).unwrap_or_default()
+    }
+    /// Time zone the reminder is scheduled in.
+    pub(crate) fn zone(&self, reminder: &Reminder) -> Zone {
+        match (reminder.time_zone, self.time_zone, self.travel_mode.unwrap_or_default()) {
+            (Some(tz), _, _) | (None, Some(tz), TravelMode::StayHome) => Zone::Named(tz),
+            _ => Zone::System,
+        }
+    }
+    /// Occurrence of the reminder at the local date and time of its time zone, or the time it
+    /// was moved to, unless its [`DstPolicy`](crate::DstPolicy) skips it.
+    pub(crate) fn occurrence<'a>(&self, reminder: &'a Reminder, scheduled: NaiveDateTime, state: &NotifierState) -> Option<Occurrence<'a>> {
+        let local = reminder.override_for(scheduled)
+            .and_then(|occurrence| occurrence.time)
+            .map_or(scheduled, |time| scheduled.date().and_time(time));
+        let at = reminder.dst.unwrap_or_default().resolve(self.zone(reminder), local)?;
+        Some(Occurrence {
+            reminder,
+            scheduled,
+            at,
+            deadline: self.deadline(reminder),
+            remaining: reminder.quota.map(|quota| quota.remaining(scheduled.date(), state.quota(reminder.id()))),
+        })
+    }
+    /// Date and time the reminder is due, when it is a deadline.
+    fn deadline(&self, reminder: &Reminder) -> Option<NaiveDateTime> {
+        self.deadlines.iter()
+            .find(|(_, deadlines)| deadlines.iter().any(|deadline| deadline.reminder.id() == reminder.id()))
+            .map(|(due, _)| *due)
+    }
+    /// Occurrences due from `first` to `last`, both inclusive, in chronological order.
+    ///
+    /// Spaced repetition and quota reminders are due according to their progress in the state.
+    pub(crate) fn occurrences_within(&self, first: DateTime<Utc>, last: DateTime<Utc>, state: &NotifierState) -> Vec<Occurrence<'_>> {
+        // Local dates in any time zone are at most a day away from the dates in UTC.
+        let start = first.date_naive() - Duration::days(1);
+        let end = last.date_naive() + Duration::days(1);
+        let dates = || start.iter_days().take_while(move |date| *date <= end);
+        let daily = dates()
+            .flat_map(|date| {
+                self.reminders.iter().flat_map(move |(time, reminders)| {
+                    reminders.iter().flat_map(move |reminder| {
+                        reminder.times(*time).into_iter().map(move |time| (reminder, date.and_time(time)))
+                    })
+                })
+            });
+        let once = self.once.iter().flat_map(|(date, reminders)| {
+            reminders.iter().flat_map(move |reminder| {
+                reminder.times(date.time()).into_iter().map(move |time| (reminder, date.date().and_time(time)))
+            })
+        });
+        // Cron schedules may have a time every second, so only the times that may fall within the
+        // window are gone through, along with the ones overridden to another time.
+        let cron = self.cron.iter().flat_map(|(schedule, reminders)| {
+            reminders.iter().flat_map(move |reminder| {
+                let (from, to) = self.zone(reminder).local_window(first, last);
+                let times = from.date().iter_days()
+                    .take_while(move |date| *date <= to.date())
+                    .filter(|date| schedule.occurs_on(*date))
+                    .flat_map(move |date| {
+                        let start = if date == from.date() { from.time() } else { NaiveTime::MIN };
+                        let end = if date == to.date() { to.time() } else { NaiveTime::from_hms_opt(23, 59, 59).unwrap() };
+                        schedule.times_within(start, end).map(move |time| date.and_time(time))
+                    });
+                let moved = reminder.overrides.iter()
+                    .map(|occurrence| occurrence.scheduled)
+                    .filter(move |scheduled| *scheduled < from || *scheduled > to)
+                    .filter(|scheduled| {
+                        schedule.occurs_on(scheduled.date())
+                            && schedule.times_within(scheduled.time(), scheduled.time()).next().is_some()
+                    });
+                times.chain(moved).map(move |scheduled| (reminder, scheduled))
+            })
+        });
+        let deadlines = self.deadlines.iter().flat_map(|(due, deadlines)| {
+            deadlines.iter().flat_map(move |deadline| {
+                std::iter::once(*due)
+                    .chain(deadline.lead.iter().map(move |lead| *due - *lead))
+                    .map(move |scheduled| (&deadline.reminder, scheduled))
+            })
+        });
+        // Steps of routines follow the dates they are anchored on, which may be a day away.
+        let routines = self.routines.iter().flat_map(|routine| {
+            let spread = Duration::days(routine.spread());
+            (start - spread).iter_days()
+                .take_while(move |date| *date <= end + spread)
+                .filter(|date| routine.occurs_on(*date))
+                .flat_map(move |date| {
+                    routine.steps.iter()
+                        .filter(move |step| step.reminder.occurs_on(date, &self.holidays))
+                        .map(move |step| (&step.reminder, date.and_time(routine.anchor) + step.offset))
+                })
+        });
+        let mut occurrences: Vec<_> = daily.chain(once).chain(cron).chain(deadlines)
+            .filter(|(reminder, scheduled)| reminder.occurs_on(scheduled.date(), &self.holidays))
+            .chain(routines)
+            .filter(|(reminder, scheduled)| {
+                reminder.spaced.as_ref()
+                    .is_none_or(|spaced| spaced.occurs_on(scheduled.date(), state.review(reminder.id())))
+            })
+            .filter_map(|(reminder, scheduled)| self.occurrence(reminder, scheduled, state))
+            .filter(|occurrence| occurrence.reminder.rrule.as_ref().is_none_or(|rrule| rrule.allows(occurrence.scheduled, occurrence.at)))
+            .filter(|occurrence| occurrence.remaining != Some(0))
+            .filter(|occurrence| occurrence.at >= first && occurrence.at <= last)
+            .collect();
+        occurrences.sort_by_key(|occurrence| occurrence.at);
+        occurrences
+    }
+}
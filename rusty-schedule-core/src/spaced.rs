@@ -0,0 +1,112 @@
+use std::num::NonZeroU32;
+
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Repeats a reminder at growing intervals, to review what was learned before it is forgotten.
+///
+/// The reminder is due a step of the ladder after the start date, and every day after until it
+/// is acknowledged, which moves it up the ladder, or failed, which starts the ladder over.
+/// Intervals are counted from the date of the occurrence acknowledged or failed, and stay at the
+/// last step once the top of the ladder is reached.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SpacedRepetition {
+    pub start: NaiveDate,
+    /// Days between reviews.
+    #[serde(default = "default_ladder")]
+    pub ladder: Vec<NonZeroU32>,
+}
+
+fn default_ladder() -> Vec<NonZeroU32> {
+    [1, 3, 7, 14, 30].into_iter().filter_map(NonZeroU32::new).collect()
+}
+
+/// How far a reminder made it up its ladder.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReviewProgress {
+    /// Step of the ladder the reminder is on.
+    pub step: usize,
+    /// Date the interval of the step is counted from.
+    pub since: NaiveDate,
+}
+
+impl SpacedRepetition {
+    pub fn new(start: NaiveDate) -> Self {
+        SpacedRepetition {
+            start,
+            ladder: default_ladder(),
+        }
+    }
+    /// Date the next review is due.
+    pub fn due(&self, progress: Option<ReviewProgress>) -> NaiveDate {
+        let progress = progress.unwrap_or(ReviewProgress {
+            step: 0,
+            since: self.start,
+        });
+        let days = self.ladder.get(progress.step)
+            .or(self.ladder.last())
+            .map_or(1, |days| days.get());
+        progress.since + Days::new(days.into())
+    }
+    pub fn occurs_on(&self, date: NaiveDate, progress: Option<ReviewProgress>) -> bool {
+        date >= self.due(progress)
+    }
+    /// Progress after reviewing on the date.
+    pub(crate) fn advance(&self, progress: Option<ReviewProgress>, date: NaiveDate) -> ReviewProgress {
+        let step = progress.map_or(0, |progress| progress.step) + 1;
+        ReviewProgress {
+            step: step.min(self.ladder.len().saturating_sub(1)),
+            since: date,
+        }
+    }
+    /// Progress after failing a review on the date.
+    pub(crate) fn reset(&self, date: NaiveDate) -> ReviewProgress {
+        ReviewProgress {
+            step: 0,
+            since: date,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn reviews_walk_up_the_ladder() {
+        let spaced = SpacedRepetition::new(date(2026, 6, 1));
+        let mut progress = None;
+        let mut due = Vec::new();
+        for _ in 0..5 {
+            let date = spaced.due(progress);
+            due.push(date);
+            progress = Some(spaced.advance(progress, date));
+        }
+        assert_eq!(due, vec![date(2026, 6, 2), date(2026, 6, 5), date(2026, 6, 12), date(2026, 6, 26), date(2026, 7, 26)]);
+    }
+
+    #[test]
+    fn top_step_stays_in_place() {
+        let spaced = SpacedRepetition::new(date(2026, 6, 1));
+        let top = ReviewProgress { step: 4, since: date(2026, 6, 1) };
+        let progress = spaced.advance(Some(top), date(2026, 7, 1));
+        assert_eq!(progress, ReviewProgress { step: 4, since: date(2026, 7, 1) });
+        assert_eq!(spaced.due(Some(progress)), date(2026, 7, 31));
+    }
+
+    #[test]
+    fn failure_starts_the_ladder_over() {
+        let spaced = SpacedRepetition::new(date(2026, 6, 1));
+        let progress = ReviewProgress { step: 3, since: date(2026, 6, 1) };
+        assert_eq!(spaced.due(Some(progress)), date(2026, 6, 15));
+        let progress = spaced.reset(date(2026, 6, 15));
+        assert_eq!(progress.step, 0);
+        assert_eq!(spaced.due(Some(progress)), date(2026, 6, 16));
+        assert!(!spaced.occurs_on(date(2026, 6, 15), Some(progress)));
+        assert!(spaced.occurs_on(date(2026, 6, 17), Some(progress)));
+    }
+}
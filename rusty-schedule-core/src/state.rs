@@ -0,0 +1,196 @@
+use std::{collections::HashMap, fs::File, io::{self, Read, Write}, path::Path};
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Completion, NotifierLoadError, QuotaProgress, ReminderId, ReviewProgress};
+
+/// How long occurrences are remembered after the latest check.
+const RETENTION: Duration = Duration::days(7);
+
+/// Identifies an occurrence of a reminder.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OccurrenceKey {
+    pub reminder: ReminderId,
+    /// Local date and time in the time zone of the reminder.
+    pub scheduled: NaiveDateTime,
+}
+
+/// Lifecycle of an occurrence.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OccurrenceStatus {
+    /// Not notified yet.
+    #[default]
+    Pending,
+    /// Notified.
+    Fired,
+    /// Notified, and to be notified again once the snooze ends.
+    Snoozed {
+        until: DateTime<Utc>,
+    },
+    /// Notified `attempt` times without being acknowledged, and to be notified again at `until`
+    /// because of its [`Escalation`](crate::Escalation).
+    Escalating {
+        attempt: u32,
+        until: DateTime<Utc>,
+    },
+    /// Acknowledged by the user.
+    Acknowledged,
+    /// Acknowledged by the user as failed, such as a review that was not remembered.
+    Failed,
+    /// Due while not checking, and not notified because of its catch-up policy.
+    Missed,
+}
+
+/// Runtime state of a notifier, persisted next to its reminders.
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct NotifierState {
+    /// Instant of the latest check for reminders.
+    #[serde(default)]
+    pub(crate) last_checked: Option<DateTime<Utc>>,
+    #[serde(default, with = "records")]
+    occurrences: HashMap<OccurrenceKey, OccurrenceStatus>,
+    /// Progress of spaced repetition reminders along their ladder.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    reviews: HashMap<ReminderId, ReviewProgress>,
+    /// Progress of quota reminders in their current period.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    quotas: HashMap<ReminderId, QuotaProgress>,
+    /// Occurrences marked done or failed, which are kept when the occurrences are pruned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    log: Vec<Completion>,
+}
+
+impl NotifierState {
+    /// Loads the state, which is empty when nothing was saved yet.
+    pub(crate) fn load(path: impl AsRef<Path>) -> Result<Self, NotifierLoadError> {
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(NotifierState::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut read = Vec::default();
+        file.read_to_end(&mut read)?;
+        let state = serde_json::de::from_slice(&read)?;
+        Ok(state)
+    }
+    pub(crate) fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        let saved_data = serde_json::ser::to_vec(self).unwrap();
+        file.write_all(&saved_data)?;
+        Ok(())
+    }
+    pub(crate) fn status(&self, key: &OccurrenceKey) -> OccurrenceStatus {
+        self.occurrences.get(key).copied().unwrap_or_default()
+    }
+    pub(crate) fn set_status(&mut self, key: OccurrenceKey, status: OccurrenceStatus) {
+        if status == OccurrenceStatus::Pending {
+            self.occurrences.remove(&key);
+        } else {
+            self.occurrences.insert(key, status);
+        }
+    }
+    pub(crate) fn review(&self, id: &ReminderId) -> Option<ReviewProgress> {
+        self.reviews.get(id).copied()
+    }
+    pub(crate) fn set_review(&mut self, id: ReminderId, progress: ReviewProgress) {
+        self.reviews.insert(id, progress);
+    }
+    pub(crate) fn quota(&self, id: &ReminderId) -> Option<QuotaProgress> {
+        self.quotas.get(id).copied()
+    }
+    pub(crate) fn set_quota(&mut self, id: ReminderId, progress: QuotaProgress) {
+        self.quotas.insert(id, progress);
+    }
+    /// Records the completion, replacing an earlier one of the same occurrence.
+    pub(crate) fn log(&mut self, completion: Completion) {
+        match self.log.iter_mut().find(|logged| logged.key == completion.key) {
+            Some(logged) => *logged = completion,
+            None => self.log.push(completion),
+        }
+    }
+    /// Logged completions of the reminder.
+    pub(crate) fn completions(&self, id: &ReminderId) -> impl Iterator<Item = &Completion> {
+        let id = id.clone();
+        self.log.iter().filter(move |completion| completion.key.reminder == id)
+    }
+    /// Occurrences to notify again by `now`, because their snooze ended or they are escalating,
+    /// along with the attempt to notify them for.
+    pub(crate) fn renotify_until(&self, now: DateTime<Utc>) -> Vec<(OccurrenceKey, u32)> {
+        self.occurrences.iter()
+            .filter_map(|(key, status)| match status {
+                OccurrenceStatus::Snoozed { until } if *until <= now => Some((key.clone(), 1)),
+                OccurrenceStatus::Escalating { attempt, until } if *until <= now => Some((key.clone(), attempt + 1)),
+                _ => None,
+            })
+            .collect()
+    }
+    /// Earliest end of a snooze or wait for an acknowledgement.
+    pub(crate) fn next_renotify(&self) -> Option<DateTime<Utc>> {
+        self.occurrences.values()
+            .filter_map(|status| match status {
+                OccurrenceStatus::Snoozed { until } | OccurrenceStatus::Escalating { until, .. } => Some(*until),
+                _ => None,
+            })
+            .min()
+    }
+    /// Latest occurrence of the reminder that was notified.
+    pub(crate) fn latest_notified(&self, id: &ReminderId) -> Option<OccurrenceKey> {
+        self.occurrences.iter()
+            .filter(|(key, status)| key.reminder == *id && matches!(status, OccurrenceStatus::Fired | OccurrenceStatus::Snoozed { .. } | OccurrenceStatus::Escalating { .. }))
+            .map(|(key, _)| key)
+            .max_by_key(|key| key.scheduled)
+            .cloned()
+    }
+    /// Forgets all occurrences of a reminder.
+    pub(crate) fn forget_reminder(&mut self, id: &ReminderId) {
+        self.occurrences.retain(|key, _| key.reminder != *id);
+        self.reviews.remove(id);
+        self.quotas.remove(id);
+        self.log.retain(|completion| completion.key.reminder != *id);
+    }
+    /// Forgets occurrences scheduled long before the latest check, unless they are still snoozed
+    /// or escalating.
+    pub(crate) fn prune(&mut self) {
+        if let Some(last_checked) = self.last_checked {
+            self.occurrences.retain(|key, status| {
+                matches!(status, OccurrenceStatus::Snoozed { .. } | OccurrenceStatus::Escalating { .. }) || key.scheduled >= (last_checked - RETENTION).naive_utc()
+            });
+        }
+    }
+}
+
+/// Occurrences are saved as a list, since JSON keys can only be strings.
+mod records {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{OccurrenceKey, OccurrenceStatus};
+
+    #[derive(Serialize)]
+    struct RecordRef<'a> {
+        #[serde(flatten)]
+        key: &'a OccurrenceKey,
+        #[serde(flatten)]
+        status: &'a OccurrenceStatus,
+    }
+
+    #[derive(Deserialize)]
+    struct Record {
+        #[serde(flatten)]
+        key: OccurrenceKey,
+        #[serde(flatten)]
+        status: OccurrenceStatus,
+    }
+
+    pub(super) fn serialize<S: Serializer>(occurrences: &HashMap<OccurrenceKey, OccurrenceStatus>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(occurrences.iter().map(|(key, status)| RecordRef { key, status }))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<OccurrenceKey, OccurrenceStatus>, D::Error> {
+        let records = Vec::<Record>::deserialize(deserializer)?;
+        Ok(records.into_iter().map(|record| (record.key, record.status)).collect())
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Days, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Notifier, OccurrenceKey, OccurrenceStatus, QuotaPeriod, ReminderId};
+
+/// Entry of the completion log, recording whether an occurrence was done.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Completion {
+    #[serde(flatten)]
+    pub key: OccurrenceKey,
+    pub outcome: Outcome,
+    /// When the occurrence was marked.
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Done,
+    Failed,
+}
+
+/// Runs of consecutive occurrences of a reminder that were done.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Streak {
+    /// Run up to the latest occurrence, which does not break it while it can still be done.
+    pub current: u32,
+    pub longest: u32,
+}
+
+/// How many occurrences of a reminder were done in a week.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WeekCompletion {
+    /// Monday the week starts on.
+    pub week: NaiveDate,
+    pub done: u32,
+    /// Occurrences due in the week.
+    pub total: u32,
+}
+
+impl WeekCompletion {
+    /// Share of the occurrences that were done, `None` when nothing was due.
+    pub fn rate(&self) -> Option<f64> {
+        (self.total > 0).then(|| f64::from(self.done) / f64::from(self.total))
+    }
+}
+
+impl Notifier {
+    /// Logged completions of the reminder, in chronological order.
+    pub fn completions(&self, id: &ReminderId) -> Vec<&Completion> {
+        let mut completions: Vec<_> = self.state.completions(id).collect();
+        completions.sort_by_key(|completion| completion.key.scheduled);
+        completions
+    }
+    /// Current and longest streaks of the reminder, since its first logged completion.
+    ///
+    /// Occurrences are the ones due by the current schedule of the reminder along with the logged
+    /// ones. Spaced repetition and quota reminders are due depending on their progress, so they
+    /// only count their logged ones.
+    pub fn streak(&self, id: &ReminderId) -> Streak {
+        let Some(first) = self.state.completions(id).map(|completion| completion.key.scheduled).min() else {
+            return Streak::default();
+        };
+        let history = self.history(id, first);
+        let mut streak = Streak::default();
+        let mut run = 0;
+        for (_, outcome) in &history {
+            run = if *outcome == Some(Outcome::Done) { run + 1 } else { 0 };
+            streak.longest = streak.longest.max(run);
+        }
+        let mut recent = history.iter().rev().peekable();
+        // The latest occurrence can still be done, unless it was missed.
+        if let Some((key, None)) = recent.peek() {
+            if self.state.status(key) != OccurrenceStatus::Missed {
+                recent.next();
+            }
+        }
+        streak.current = recent.take_while(|(_, outcome)| *outcome == Some(Outcome::Done)).count() as u32;
+        streak
+    }
+    /// Completion of the reminder over the latest `weeks` weeks, the current one last.
+    pub fn weekly_completion(&self, id: &ReminderId, weeks: usize) -> Vec<WeekCompletion> {
+        let Some(reminder) = self.schedule.get(id) else { return Vec::new() };
+        let today = self.schedule.zone(reminder).local(self.clock.now()).date();
+        let current = QuotaPeriod::Week.start(today);
+        let mut completion: Vec<_> = (0..weeks as u64).rev()
+            .map(|ago| WeekCompletion {
+                week: current - Days::new(7 * ago),
+                done: 0,
+                total: 0,
+            })
+            .collect();
+        let Some(first) = completion.first().map(|week| week.week.and_time(NaiveTime::MIN)) else {
+            return completion;
+        };
+        for (key, outcome) in self.history(id, first) {
+            let week = QuotaPeriod::Week.start(key.scheduled.date());
+            if let Some(week) = completion.iter_mut().find(|completion| completion.week == week) {
+                week.total += 1;
+                week.done += u32::from(outcome == Some(Outcome::Done));
+            }
+        }
+        completion
+    }
+    /// Occurrences of the reminder from `first` until now, along with their logged outcome, in
+    /// chronological order.
+    ///
+    /// Whether spaced repetition and quota reminders were due in the past depends on their
+    /// progress back then, rather than now, so only their logged occurrences are included.
+    fn history(&self, id: &ReminderId, first: NaiveDateTime) -> Vec<(OccurrenceKey, Option<Outcome>)> {
+        let Some(reminder) = self.schedule.get(id) else { return Vec::new() };
+        let mut history: BTreeMap<NaiveDateTime, Option<Outcome>> = BTreeMap::new();
+        if reminder.spaced.is_none() && reminder.quota.is_none() {
+            let now = self.clock.now();
+            let start = self.schedule.zone(reminder).instant(first).earliest().unwrap_or(first.and_utc());
+            history.extend(self.schedule.occurrences_within(start, now, &self.state).into_iter()
+                .filter(|occurrence| occurrence.reminder.id() == id)
+                .map(|occurrence| (occurrence.scheduled, None)));
+        }
+        for completion in self.state.completions(id).filter(|completion| completion.key.scheduled >= first) {
+            history.insert(completion.key.scheduled, Some(completion.outcome));
+        }
+        history.into_iter()
+            .map(|(scheduled, outcome)| (OccurrenceKey { reminder: id.clone(), scheduled }, outcome))
+            .collect()
+    }
+}
@@ -0,0 +1,10 @@
+//! Placeholders such as `{due}` in the content of reminders, filled in when they are notified.
+
+/// Replaces every `{name}` of the variables in the text, leaving unknown placeholders as they are.
+pub(crate) fn render(text: &str, variables: &[(&str, String)]) -> String {
+    let mut rendered = text.to_owned();
+    for (name, value) in variables {
+        rendered = rendered.replace(&format!("{{{name}}}"), value);
+    }
+    rendered
+}
@@ -0,0 +1,50 @@
+//! Utilities for deterministic tests of schedules.
+
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::Clock;
+
+/// Clock that only moves when told to.
+///
+/// Clones share the same time, so a clone kept by a test moves the clock of the notifier it was
+/// given to:
+/// ```
+/// use chrono::{Duration, Local, NaiveTime, TimeZone};
+/// use rusty_schedule_core::{NotifierBuilder, Reminder, testing::FakeClock};
+///
+/// let clock = FakeClock::new(Local.with_ymd_and_hms(2026, 11, 3, 8, 0, 0).unwrap());
+/// let mut notifier = NotifierBuilder::default()
+///     .notify(NaiveTime::from_hms_opt(8, 30, 0).unwrap(), Reminder::new("JOB", "IT'S JOB TIME"))
+///     .clock(clock.clone())
+///     .finish();
+/// assert_eq!(notifier.check_reminders().fired().len(), 0);
+/// clock.advance(Duration::minutes(30));
+/// assert_eq!(notifier.check_reminders().fired().len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FakeClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl FakeClock {
+    pub fn new<Tz: TimeZone>(now: DateTime<Tz>) -> Self {
+        FakeClock {
+            now: Arc::new(Mutex::new(now.with_timezone(&Utc))),
+        }
+    }
+    pub fn set<Tz: TimeZone>(&self, now: DateTime<Tz>) {
+        *self.now.lock().unwrap() = now.with_timezone(&Utc);
+    }
+    /// Moves the clock by `duration`, which goes back in time when negative.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Duration, Local, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Time zone that reminders without their own are notified in, when travelling away from the
+/// time zone of the notifier.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TravelMode {
+    /// Notify in the time zone of the notifier, at the same instant wherever the system is.
+    #[default]
+    StayHome,
+    /// Notify in the time zone of the system, at the same local time wherever the system is.
+    FollowSystem,
+}
+
+/// Time zone an occurrence is scheduled in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Zone {
+    /// Time zone of the system.
+    System,
+    Named(Tz),
+}
+
+impl Zone {
+    /// Instants the local date and time of the zone refers to.
+    pub fn instant(&self, local: NaiveDateTime) -> LocalResult<DateTime<Utc>> {
+        match self {
+            Zone::System => Local.from_local_datetime(&local).map(|instant| instant.to_utc()),
+            Zone::Named(tz) => tz.from_local_datetime(&local).map(|instant| instant.to_utc()),
+        }
+    }
+    /// Local date and time of the zone at the instant.
+    pub fn local(&self, instant: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Zone::System => instant.with_timezone(&Local).naive_local(),
+            Zone::Named(tz) => instant.with_timezone(tz).naive_local(),
+        }
+    }
+    /// Local dates and times that may be scheduled at instants from `first` to `last`, allowing
+    /// for the wall clock jumping back or forth within a day of either.
+    pub(crate) fn local_window(&self, first: DateTime<Utc>, last: DateTime<Utc>) -> (NaiveDateTime, NaiveDateTime) {
+        let offsets = |instant: DateTime<Utc>| [instant - Duration::days(1), instant, instant + Duration::days(1)]
+            .map(|near| self.local(near) - near.naive_utc());
+        let earliest = offsets(first).into_iter().min().unwrap();
+        let latest = offsets(last).into_iter().max().unwrap();
+        (first.naive_utc() + earliest, last.naive_utc() + latest)
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::{Duration, NaiveDateTime, NaiveTime, TimeZone};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{CatchUp, NotifierBuilder, Reminder, Repeat, testing::FakeClock};
+
+/// Checks before a reminder repeating hourly from 08:00 to 12:00, then again at 12:30 as if the
+/// system was suspended in between, returning the hours fired and missed.
+fn resume(catch_up: CatchUp) -> (Vec<u32>, Vec<u32>) {
+    let mut reminder = Reminder::new("HOURLY", "");
+    reminder.repeat = Some(Repeat {
+        every: Duration::hours(1),
+        until: NaiveTime::from_hms_opt(12, 0, 0),
+    });
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 7, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(catch_up)
+        .notify(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), reminder)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    clock.set(Berlin.with_ymd_and_hms(2026, 6, 1, 12, 30, 0).unwrap());
+    let check = notifier.check_reminders();
+    let hour = |scheduled: NaiveDateTime| chrono::Timelike::hour(&scheduled);
+    let fired = check.fired().iter().map(|fired| hour(fired.scheduled)).collect();
+    let missed = check.missed().iter().map(|missed| hour(missed.scheduled)).collect();
+    (fired, missed)
+}
+
+#[test]
+fn skip_misses_every_overdue_occurrence() {
+    assert_eq!(resume(CatchUp::Skip), (vec![], vec![8, 9, 10, 11, 12]));
+}
+
+#[test]
+fn fire_all_fires_every_overdue_occurrence() {
+    assert_eq!(resume(CatchUp::FireAll), (vec![8, 9, 10, 11, 12], vec![]));
+}
+
+#[test]
+fn fire_latest_only_fires_the_latest_overdue_occurrence() {
+    assert_eq!(resume(CatchUp::FireLatestOnly), (vec![12], vec![8, 9, 10, 11]));
+}
+
+#[test]
+fn fire_if_within_fires_recently_overdue_occurrences() {
+    assert_eq!(resume(CatchUp::FireIfWithin(Duration::hours(2))), (vec![11, 12], vec![8, 9, 10]));
+}
+
+#[test]
+fn first_check_without_state_misses_earlier_reminders() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 18, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), Reminder::new("JOB", ""))
+        .notify(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), Reminder::new("WORK OUT", ""))
+        .notify(NaiveTime::from_hms_opt(17, 50, 0).unwrap(), Reminder::new("DINNER", ""))
+        .clock(clock.clone())
+        .finish();
+    let check = notifier.check_reminders();
+    let fired: Vec<_> = check.fired().iter().map(|fired| fired.title.as_str()).collect();
+    let missed: Vec<_> = check.missed().iter().map(|missed| missed.title()).collect();
+    assert_eq!(fired, vec!["DINNER"]);
+    assert_eq!(missed, vec!["JOB", "WORK OUT"]);
+}
+
+#[test]
+fn restart_after_long_stop_only_catches_up_on_the_last_day() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 21, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(CatchUp::FireAll)
+        .notify(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), Reminder::new("JOB", ""))
+        .notify(NaiveTime::from_hms_opt(20, 0, 0).unwrap(), Reminder::new("DINNER", ""))
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    clock.advance(Duration::days(14));
+    let check = notifier.check_reminders();
+    let fired: Vec<_> = check.fired().iter().map(|fired| fired.title.as_str()).collect();
+    assert_eq!(fired, vec!["JOB", "DINNER"]);
+    assert!(check.missed().is_empty());
+}
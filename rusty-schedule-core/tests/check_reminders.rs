@@ -0,0 +1,84 @@
+use std::num::NonZeroU32;
+
+use chrono::{Duration, NaiveTime, TimeZone};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{CatchUp, Clock, CronSchedule, Escalation, NotifierBuilder, Reminder, MISSED_AFTER, testing::FakeClock};
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn early_morning_reminders_fire_once_per_day_across_midnights() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 12, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify(time(0, 30), Reminder::new("NIGHT", ""))
+        .notify(time(5, 0), Reminder::new("DAWN", ""))
+        .notify(time(8, 45), Reminder::new("MORNING", ""))
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    let mut fired = Vec::new();
+    let end = Berlin.with_ymd_and_hms(2026, 6, 4, 12, 0, 0).unwrap();
+    while clock.now() < end {
+        clock.advance(Duration::seconds(30));
+        for reminder in notifier.check_reminders() {
+            assert!(clock.now() - reminder.at <= MISSED_AFTER, "{} notified late", reminder.title);
+            fired.push((reminder.title, reminder.scheduled));
+        }
+    }
+    let mut expected = Vec::new();
+    for day in 2..=4 {
+        let date = Berlin.with_ymd_and_hms(2026, 6, day, 0, 0, 0).unwrap().date_naive();
+        expected.push(("NIGHT".to_owned(), date.and_time(time(0, 30))));
+        expected.push(("DAWN".to_owned(), date.and_time(time(5, 0))));
+        expected.push(("MORNING".to_owned(), date.and_time(time(8, 45))));
+    }
+    assert_eq!(fired, expected);
+}
+
+#[test]
+fn escalation_stops_after_max_attempts() {
+    let mut reminder = Reminder::new("MEDS", "");
+    reminder.escalation = Some(Escalation {
+        every: Duration::minutes(5),
+        after: None,
+        max_attempts: NonZeroU32::new(3),
+        urgency: None,
+        channel: None,
+    });
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 8, 59, 0).unwrap());
+    // The first check may look back to a previous occurrence, depending on the system time zone.
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(CatchUp::Skip)
+        .notify(time(9, 0), reminder)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    let mut attempts = Vec::new();
+    for _ in 0..60 {
+        clock.advance(Duration::minutes(1));
+        attempts.extend(notifier.check_reminders().into_iter().map(|fired| fired.attempt));
+    }
+    assert_eq!(attempts, vec![1, 2, 3]);
+}
+
+#[test]
+fn cron_every_second_fires_every_second() {
+    let schedule: CronSchedule = "* * * * * *".parse().unwrap();
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 23, 59, 58).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify_cron(schedule, Reminder::new("TICK", ""))
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    for _ in 0..5 {
+        assert_eq!(notifier.next_check().map(|next| next.to_utc()), Some(clock.now() + Duration::seconds(1)));
+        clock.advance(Duration::seconds(1));
+        let fired: Vec<_> = notifier.check_reminders().into_iter().map(|fired| fired.at).collect();
+        assert_eq!(fired, vec![clock.now()]);
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{CatchUp, NotifierBuilder, Reminder, testing::FakeClock};
+
+#[test]
+fn lead_alerts_fire_ahead_of_the_deadline() {
+    let due = NaiveDate::from_ymd_opt(2027, 4, 15).unwrap().and_hms_opt(17, 0, 0).unwrap();
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2027, 4, 1, 0, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(CatchUp::Skip)
+        .notify_deadline(due, vec![Duration::days(7), Duration::days(1), Duration::hours(1)], Reminder::new("TAXES", "TAX FILING IS DUE {due}"))
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    let mut fired: Vec<(DateTime<Utc>, String)> = Vec::new();
+    for _ in 0..20 * 24 * 6 {
+        clock.advance(Duration::minutes(10));
+        fired.extend(notifier.check_reminders().into_iter().map(|fired| (fired.at, fired.content)));
+    }
+    let at = |hours: i64| Berlin.from_local_datetime(&(due - Duration::hours(hours))).unwrap().to_utc();
+    assert_eq!(fired, vec![
+        (at(7 * 24), "TAX FILING IS DUE in 7 days".to_string()),
+        (at(24), "TAX FILING IS DUE in 1 day".to_string()),
+        (at(1), "TAX FILING IS DUE in 1 hour".to_string()),
+        (at(0), "TAX FILING IS DUE now".to_string()),
+    ]);
+}
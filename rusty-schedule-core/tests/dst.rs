@@ -0,0 +1,109 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{AmbiguousTime, Clock, CronSchedule, DstPolicy, NonexistentTime, Notifier, NotifierBuilder, Reminder, testing::FakeClock};
+
+/// Notifier in Berlin with a daily reminder at 02:30, which is skipped on the last Sunday of
+/// March and repeated on the last Sunday of October.
+fn notifier(dst: Option<DstPolicy>, clock: &FakeClock) -> Notifier {
+    let mut reminder = Reminder::new("NIGHT", "NIGHT SHIFT");
+    reminder.dst = dst;
+    NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify(NaiveTime::from_hms_opt(2, 30, 0).unwrap(), reminder)
+        .clock(clock.clone())
+        .finish()
+}
+
+/// Notifier in Berlin with a reminder at 02:30 every day by a cron expression.
+fn cron_notifier(clock: &FakeClock) -> Notifier {
+    let schedule: CronSchedule = "0 30 2 * * *".parse().unwrap();
+    NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify_cron(schedule, Reminder::new("NIGHT", "NIGHT SHIFT"))
+        .clock(clock.clone())
+        .finish()
+}
+
+fn fired_at(notifier: &mut Notifier, clock: &FakeClock, until: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut fired = Vec::new();
+    while clock.now() < until {
+        clock.advance(Duration::minutes(10));
+        fired.extend(notifier.check_reminders().into_iter().map(|fired| fired.at));
+    }
+    fired
+}
+
+#[test]
+fn spring_forward_shifts_nonexistent_time() {
+    let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap());
+    let mut notifier = notifier(None, &clock);
+    notifier.check_reminders();
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 3, 29, 6, 0, 0).unwrap());
+    assert_eq!(fired, vec![Berlin.with_ymd_and_hms(2026, 3, 29, 3, 30, 0).unwrap().to_utc()]);
+}
+
+#[test]
+fn spring_forward_skips_nonexistent_time() {
+    let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap());
+    let dst = DstPolicy { nonexistent: NonexistentTime::Skip, ..DstPolicy::default() };
+    let mut notifier = notifier(Some(dst), &clock);
+    notifier.check_reminders();
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 3, 29, 6, 0, 0).unwrap());
+    assert!(fired.is_empty());
+    let start = Utc.with_ymd_and_hms(2026, 3, 29, 0, 0, 0).unwrap();
+    let occurrences = notifier.occurrences_between(start, start + Duration::days(2));
+    assert_eq!(occurrences.len(), 1);
+    assert_eq!(occurrences[0].scheduled.date(), NaiveDate::from_ymd_opt(2026, 3, 30).unwrap());
+}
+
+#[test]
+fn fall_back_notifies_ambiguous_time_once() {
+    for (ambiguous, hour) in [(AmbiguousTime::Earliest, 0), (AmbiguousTime::Latest, 1)] {
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 10, 24, 22, 0, 0).unwrap());
+        let dst = DstPolicy { ambiguous, ..DstPolicy::default() };
+        let mut notifier = notifier(Some(dst), &clock);
+        notifier.check_reminders();
+        let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 10, 25, 6, 0, 0).unwrap());
+        assert_eq!(fired, vec![Utc.with_ymd_and_hms(2026, 10, 25, hour, 30, 0).unwrap()]);
+    }
+}
+
+#[test]
+fn clock_set_back_does_not_notify_again() {
+    let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let mut notifier = notifier(None, &clock);
+    notifier.check_reminders();
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 6, 1, 1, 0, 0).unwrap());
+    assert_eq!(fired.len(), 1);
+    clock.advance(Duration::hours(-2));
+    assert!(notifier.check_reminders().fired().is_empty());
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 6, 2, 1, 0, 0).unwrap());
+    assert_eq!(fired, vec![Utc.with_ymd_and_hms(2026, 6, 2, 0, 30, 0).unwrap()]);
+}
+
+#[test]
+fn clock_set_back_notifies_occurrences_not_yet_due() {
+    let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let mut notifier = notifier(None, &clock);
+    // The first check records the occurrence of the day before as missed.
+    assert_eq!(notifier.check_reminders().missed().len(), 1);
+    clock.advance(Duration::days(-2));
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 6, 1, 1, 0, 0).unwrap());
+    assert_eq!(fired, vec![
+        Utc.with_ymd_and_hms(2026, 5, 30, 0, 30, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 6, 1, 0, 30, 0).unwrap(),
+    ]);
+}
+
+#[test]
+fn cron_follows_dst_policy() {
+    let clock = FakeClock::new(Utc.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap());
+    let mut notifier = cron_notifier(&clock);
+    notifier.check_reminders();
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 3, 29, 6, 0, 0).unwrap());
+    assert_eq!(fired, vec![Berlin.with_ymd_and_hms(2026, 3, 29, 3, 30, 0).unwrap().to_utc()]);
+    clock.set(Utc.with_ymd_and_hms(2026, 10, 24, 22, 0, 0).unwrap());
+    notifier.check_reminders();
+    let fired = fired_at(&mut notifier, &clock, Utc.with_ymd_and_hms(2026, 10, 25, 6, 0, 0).unwrap());
+    assert_eq!(fired, vec![Utc.with_ymd_and_hms(2026, 10, 25, 0, 30, 0).unwrap()]);
+}
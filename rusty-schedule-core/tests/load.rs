@@ -0,0 +1,17 @@
+use std::fs;
+
+use rusty_schedule_core::Notifier;
+
+#[test]
+fn load_writes_back_ids_without_pruning() {
+    let dir = std::env::temp_dir().join(format!("rusty-schedule-load-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("reminders.json");
+    fs::write(&path, r#"{"prune_expired":true,"reminders":{"9:00:00":[{"title":"GARDEN","content":"WATER THE GARDEN","until":"2000-01-01"}]}}"#).unwrap();
+    let notifier = Notifier::load(&path).unwrap();
+    let id = notifier.reminders().next().unwrap().id().clone();
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(saved.contains("GARDEN"));
+    assert!(saved.contains(&id.to_string()));
+    fs::remove_dir_all(&dir).unwrap();
+}
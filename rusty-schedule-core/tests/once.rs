@@ -0,0 +1,44 @@
+use std::fs;
+
+use chrono::{Duration, NaiveDate, TimeZone};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{Notifier, NotifierBuilder, Reminder, testing::FakeClock};
+
+fn checked(notifier: &mut Notifier, clock: &FakeClock, hours: i64) -> Vec<String> {
+    let mut fired = Vec::new();
+    for _ in 0..hours * 6 {
+        clock.advance(Duration::minutes(10));
+        fired.extend(notifier.check_reminders().into_iter().map(|fired| fired.title));
+    }
+    fired
+}
+
+#[test]
+fn one_off_reminder_fires_once_across_save_and_load() {
+    let dir = std::env::temp_dir().join(format!("rusty-schedule-once-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("reminders.json");
+    let due = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 7, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify_once(due, Reminder::new("DENTIST", "DENTIST APPOINTMENT"))
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    notifier.save(&path).unwrap();
+    notifier.save_state(&path).unwrap();
+
+    let mut notifier = Notifier::load(&path).unwrap();
+    notifier.set_clock(clock.clone());
+    assert_eq!(checked(&mut notifier, &clock, 4), vec!["DENTIST"]);
+    notifier.save(&path).unwrap();
+    notifier.save_state(&path).unwrap();
+
+    let mut notifier = Notifier::load(&path).unwrap();
+    notifier.set_clock(clock.clone());
+    assert!(checked(&mut notifier, &clock, 48).is_empty());
+    // Notified one-off reminders stay in the file.
+    assert_eq!(notifier.reminders().count(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
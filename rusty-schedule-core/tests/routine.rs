@@ -0,0 +1,68 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{CatchUp, Notifier, NotifierBuilder, Reminder, Routine, testing::FakeClock};
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 6, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+}
+
+/// Notifier in Berlin with a bedtime routine anchored at 00:30 on Saturdays, starting at 23:00 the
+/// evening before.
+fn notifier(clock: &FakeClock) -> Notifier {
+    let mut routine = Routine::new("BEDTIME", NaiveTime::from_hms_opt(0, 30, 0).unwrap())
+        .step(Duration::minutes(-90), Reminder::new("WIND DOWN", "PUT THE SCREENS AWAY"))
+        .step(Duration::minutes(-30), Reminder::new("LIGHTS", "DIM THE LIGHTS"))
+        .step(Duration::zero(), Reminder::new("SLEEP", "GO TO SLEEP"));
+    routine.weekdays = Some(vec![Weekday::Sat]);
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(CatchUp::Skip)
+        .routine(routine)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    notifier
+}
+
+fn checked(notifier: &mut Notifier, clock: &FakeClock, hours: i64) -> Vec<(NaiveDateTime, String)> {
+    let mut fired = Vec::new();
+    for _ in 0..hours * 6 {
+        clock.advance(Duration::minutes(10));
+        fired.extend(notifier.check_reminders().into_iter().map(|fired| (fired.scheduled, fired.title)));
+    }
+    fired
+}
+
+#[test]
+fn steps_before_the_anchor_cross_midnight() {
+    // June 5, 2026 is a Friday.
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 5, 12, 0, 0).unwrap());
+    let mut notifier = notifier(&clock);
+    assert_eq!(checked(&mut notifier, &clock, 7 * 24), vec![
+        (at(5, 23, 0), "WIND DOWN".to_string()),
+        (at(6, 0, 0), "LIGHTS".to_string()),
+        (at(6, 0, 30), "SLEEP".to_string()),
+    ]);
+}
+
+#[test]
+fn moving_the_anchor_moves_every_step() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 5, 12, 0, 0).unwrap());
+    let mut notifier = notifier(&clock);
+    notifier.routine_mut("BEDTIME").unwrap().anchor = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+    assert_eq!(checked(&mut notifier, &clock, 7 * 24), vec![
+        (at(6, 22, 0), "WIND DOWN".to_string()),
+        (at(6, 23, 0), "LIGHTS".to_string()),
+        (at(6, 23, 30), "SLEEP".to_string()),
+    ]);
+}
+
+#[test]
+fn disabled_routine_is_not_notified() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 5, 12, 0, 0).unwrap());
+    let mut notifier = notifier(&clock);
+    notifier.routine_mut("BEDTIME").unwrap().enabled = false;
+    assert!(checked(&mut notifier, &clock, 7 * 24).is_empty());
+    notifier.routine_mut("BEDTIME").unwrap().enabled = true;
+    assert_eq!(checked(&mut notifier, &clock, 7 * 24).len(), 3);
+}
@@ -0,0 +1,29 @@
+use chrono::{Duration, NaiveTime, TimeZone};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{Clock, NotifierBuilder, Reminder, SnoozeError, testing::FakeClock};
+
+#[test]
+fn snoozing_needs_a_notified_occurrence() {
+    let reminder = Reminder::new("JOB", "IT'S JOB TIME");
+    let id = reminder.id().clone();
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 7, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), reminder)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    assert!(matches!(notifier.snooze(&id, Duration::minutes(10)), Err(SnoozeError::NotNotified(_))));
+    let mut fired = Vec::new();
+    for _ in 0..120 {
+        clock.advance(Duration::minutes(1));
+        fired.extend(notifier.check_reminders().into_iter().map(|_| clock.now()));
+        if clock.now() == Berlin.with_ymd_and_hms(2026, 6, 1, 8, 0, 0).unwrap() {
+            notifier.snooze(&id, Duration::minutes(10)).unwrap();
+        }
+    }
+    assert_eq!(fired, vec![
+        Berlin.with_ymd_and_hms(2026, 6, 1, 8, 0, 0).unwrap().to_utc(),
+        Berlin.with_ymd_and_hms(2026, 6, 1, 8, 10, 0).unwrap().to_utc(),
+    ]);
+}
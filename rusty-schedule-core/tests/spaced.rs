@@ -0,0 +1,91 @@
+use chrono::{Duration, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{CatchUp, Clock, Notifier, NotifierBuilder, OccurrenceKey, Reminder, ReminderId, SpacedRepetition, testing::FakeClock};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Notifier in Berlin with a review at 09:00 whose ladder starts on June 1.
+fn notifier(clock: &FakeClock) -> (Notifier, ReminderId) {
+    let mut reminder = Reminder::new("VOCAB", "REVIEW VOCABULARY");
+    reminder.spaced = Some(SpacedRepetition::new(date(2026, 6, 1)));
+    let id = reminder.id().clone();
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .catch_up(CatchUp::Skip)
+        .notify(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), reminder)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    (notifier, id)
+}
+
+/// Checks every hour for a day, returning the occurrences notified.
+fn next_day(notifier: &mut Notifier, clock: &FakeClock) -> Vec<OccurrenceKey> {
+    let mut fired = Vec::new();
+    for _ in 0..24 {
+        clock.advance(Duration::hours(1));
+        fired.extend(notifier.check_reminders().into_iter().map(|fired| fired.key()));
+    }
+    fired
+}
+
+/// Dates the review is notified on before `until`, failing it on the dates for which `fail`
+/// holds and acknowledging it otherwise.
+fn reviews(notifier: &mut Notifier, clock: &FakeClock, until: NaiveDate, fail: impl Fn(NaiveDate) -> bool) -> Vec<NaiveDate> {
+    let mut dates = Vec::new();
+    while clock.now().with_timezone(&Berlin).date_naive() < until {
+        for key in next_day(notifier, clock) {
+            dates.push(key.scheduled.date());
+            if fail(key.scheduled.date()) {
+                notifier.fail(&key);
+            } else {
+                notifier.acknowledge(&key);
+            }
+        }
+    }
+    dates
+}
+
+#[test]
+fn acknowledged_reviews_walk_up_the_ladder() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let (mut notifier, id) = notifier(&clock);
+    let dates = reviews(&mut notifier, &clock, date(2026, 9, 1), |_| false);
+    assert_eq!(dates, vec![
+        date(2026, 6, 2),
+        date(2026, 6, 5),
+        date(2026, 6, 12),
+        date(2026, 6, 26),
+        date(2026, 7, 26),
+        date(2026, 8, 25),
+    ]);
+    // The top step stays in place.
+    let progress = notifier.review_progress(&id).unwrap();
+    assert_eq!((progress.step, progress.since), (4, date(2026, 8, 25)));
+}
+
+#[test]
+fn failed_review_starts_the_ladder_over() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let (mut notifier, id) = notifier(&clock);
+    let dates = reviews(&mut notifier, &clock, date(2026, 6, 20), |failed| failed == date(2026, 6, 12));
+    assert_eq!(dates, vec![
+        date(2026, 6, 2),
+        date(2026, 6, 5),
+        date(2026, 6, 12),
+        date(2026, 6, 13),
+        date(2026, 6, 16),
+    ]);
+    assert_eq!(notifier.review_progress(&id).unwrap().step, 2);
+}
+
+#[test]
+fn unanswered_review_is_due_every_day() {
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap());
+    let (mut notifier, id) = notifier(&clock);
+    let dates: Vec<_> = (0..3).flat_map(|_| next_day(&mut notifier, &clock)).map(|key| key.scheduled.date()).collect();
+    assert_eq!(dates, vec![date(2026, 6, 2), date(2026, 6, 3)]);
+    assert_eq!(notifier.review_progress(&id), None);
+}
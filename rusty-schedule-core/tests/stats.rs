@@ -0,0 +1,41 @@
+use std::num::NonZeroU32;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Europe::Berlin;
+use rusty_schedule_core::{NotifierBuilder, Quota, QuotaPeriod, Reminder, Streak, WeekCompletion, testing::FakeClock};
+
+#[test]
+fn quota_history_counts_only_logged_completions() {
+    let mut reminder = Reminder::new("WORKOUT", "{remaining} WORKOUTS LEFT THIS WEEK");
+    reminder.quota = Some(Quota {
+        count: NonZeroU32::new(3).unwrap(),
+        per: QuotaPeriod::Week,
+    });
+    let id = reminder.id().clone();
+    // Monday.
+    let clock = FakeClock::new(Berlin.with_ymd_and_hms(2026, 6, 1, 12, 0, 0).unwrap());
+    let mut notifier = NotifierBuilder::default()
+        .time_zone(Berlin)
+        .notify(NaiveTime::from_hms_opt(18, 0, 0).unwrap(), reminder)
+        .clock(clock.clone())
+        .finish();
+    notifier.check_reminders();
+    for _ in 0..14 {
+        clock.advance(Duration::hours(6));
+        let keys: Vec<_> = notifier.check_reminders().into_iter().map(|fired| fired.key()).collect();
+        for key in keys {
+            if matches!(key.scheduled.weekday(), Weekday::Mon | Weekday::Wed | Weekday::Fri) {
+                notifier.acknowledge(&key);
+            }
+        }
+        clock.advance(Duration::hours(18));
+        notifier.check_reminders();
+    }
+    let week = |day, done, total| WeekCompletion {
+        week: NaiveDate::from_ymd_opt(2026, 6, day).unwrap(),
+        done,
+        total,
+    };
+    assert_eq!(notifier.weekly_completion(&id, 3), vec![week(1, 3, 3), week(8, 3, 3), week(15, 0, 0)]);
+    assert_eq!(notifier.streak(&id), Streak { current: 6, longest: 6 });
+}